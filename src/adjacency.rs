@@ -0,0 +1,201 @@
+use bevy::window::PrimaryWindow;
+
+use crate::*;
+
+/// The adjacency rules and terrain effects currently in effect for a building
+#[derive(Component, Default, Debug, PartialEq)]
+pub struct Synergies {
+    // Indices into AdjacencyData.rules
+    pub rules: Vec<usize>,
+    // Indices into TerrainData.effects
+    pub terrain: Vec<usize>,
+    pub yield_bonus: Vec<(ItemType, u32)>,
+    // Faster production cycles, in percent
+    pub speed_bonus: f32,
+    // From decorations nearby
+    pub attractiveness: u32,
+}
+
+impl Synergies {
+    /// Extra production of an item, in percent
+    pub fn yield_bonus(&self, item_type: ItemType) -> u32 {
+        self.yield_bonus
+            .iter()
+            .filter(|(bonus_type, _)| *bonus_type == item_type)
+            .map(|(_, percent)| percent)
+            .sum()
+    }
+
+    /// How much faster than normal the building's production timer runs
+    pub fn speed_multiplier(&self) -> f32 {
+        1.0 + self.speed_bonus / 100.0
+    }
+
+    /// One line per bonus, for the UI
+    pub fn describe(&self, adjacency_data: &AdjacencyData, terrain_data: &TerrainData) -> Vec<String> {
+        let mut lines = self
+            .rules
+            .iter()
+            .map(|index| adjacency_data.rules[*index].description)
+            .chain(
+                self.terrain
+                    .iter()
+                    .map(|index| terrain_data.effects[*index].description),
+            )
+            .map(str::to_string)
+            .collect::<Vec<String>>();
+
+        if self.attractiveness > 0 {
+            let bonus = (self.attractiveness as f32 * adjacency_data.speed_per_attractiveness)
+                .min(adjacency_data.max_attraction_bonus);
+
+            lines.push(format!("Attractiveness {}: {bonus}% faster", self.attractiveness));
+        }
+
+        lines
+    }
+}
+
+pub struct AdjacencyPlugin;
+
+impl Plugin for AdjacencyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(draw_synergy_preview)
+            .add_systems((update_synergies, update_synergy_preview));
+    }
+}
+
+/// Re-evaluates every building's neighbours whenever something is built, moved or removed
+fn update_synergies(
+    mut commands: Commands,
+    grid_index: Res<GridIndex>,
+    tiles: Query<&Tile>,
+    buildings: Query<(Entity, &Parent, &Building, Option<&Synergies>)>,
+    adjacency_data: Res<AdjacencyData>,
+    terrain_data: Res<TerrainData>,
+) {
+    if !grid_index.is_changed() {
+        return;
+    }
+
+    for (entity, parent, building, current) in buildings.iter() {
+        let Ok(tile) = tiles.get(parent.get()) else {
+            continue;
+        };
+
+        let footprint = building.building_type.get_rotated_footprint(building.rotation);
+        let neighbours = grid_index
+            .neighbours(tile.coords(), footprint, Some(entity))
+            .into_iter()
+            .filter_map(|neighbour| {
+                buildings
+                    .get(neighbour)
+                    .ok()
+                    .map(|(_, _, building, _)| building.building_type)
+            })
+            .collect::<Vec<BuildingType>>();
+
+        let attractiveness = adjacency_data.attractiveness_at(
+            building.building_type,
+            grid_index.occupants().filter_map(|(coords, occupant)| {
+                let decoration = buildings.get(occupant).ok()?.2.building_type;
+                decoration.is_decoration().then_some((coords, decoration))
+            }),
+            tile.coords(),
+            footprint,
+        );
+
+        let mut synergies = adjacency_data.synergies(building.building_type, &neighbours, attractiveness);
+        terrain_data.apply(
+            &mut synergies,
+            building.building_type,
+            &grid_index.terrain_under(tile.coords(), footprint),
+        );
+
+        if current != Some(&synergies) {
+            commands.entity(entity).insert(synergies);
+        }
+    }
+}
+
+// Marker
+#[derive(Component)]
+struct SynergyPreviewRoot;
+
+// Marker
+#[derive(Component)]
+struct SynergyPreviewText;
+
+fn draw_synergy_preview(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let preview_width = primary_window.single().resolution.width() / 4.0;
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(preview_width), Val::Auto),
+                justify_content: JustifyContent::Center,
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Px((primary_window.single().resolution.width() - preview_width) / 2.0),
+                    bottom: Val::Percent(4.0),
+                    ..default()
+                },
+                padding: UiRect::all(Val::Px(preview_width / 60.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.17, 0.19, 0.36, 0.85).into(),
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .insert(Name::new("Synergy preview"))
+        .insert(SynergyPreviewRoot)
+        .with_children(|commands| {
+            commands
+                .spawn(TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load("font.otf"),
+                        font_size: preview_width / 22.0,
+                        color: Color::WHITE,
+                    },
+                ))
+                .insert(SynergyPreviewText);
+        });
+}
+
+/// Lists the bonuses the building being placed would get on the hovered tile
+fn update_synergy_preview(
+    preview_ghosts: Query<&ConstructPreviewGhost>,
+    adjacency_data: Res<AdjacencyData>,
+    terrain_data: Res<TerrainData>,
+    mut preview: Query<&mut Visibility, With<SynergyPreviewRoot>>,
+    mut preview_text: Query<&mut Text, With<SynergyPreviewText>>,
+) {
+    let text = preview_ghosts
+        .iter()
+        .flat_map(|ghost| ghost.synergies.describe(&adjacency_data, &terrain_data))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    for mut visibility in preview.iter_mut() {
+        let target = if text.is_empty() {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+
+        if *visibility != target {
+            *visibility = target;
+        }
+    }
+
+    for mut preview_text in preview_text.iter_mut() {
+        if preview_text.sections[0].value != text {
+            preview_text.sections[0].value = text.clone();
+        }
+    }
+}
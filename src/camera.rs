@@ -93,7 +93,11 @@ fn keys_move_camera(
     settings: Res<InputSettings>,
     keys: Res<Input<KeyCode>>,
     time: Res<Time>,
+    land: Res<Land>,
 ) {
+    // The camera can't stray far from the land the player owns
+    let (min, max) = land.camera_bounds();
+
     for mut camera_transform in camera.iter_mut() {
         let mut velocity = Vec3::ZERO;
         /*
@@ -134,10 +138,10 @@ fn keys_move_camera(
         // Normalize velocity vector so that going forward + right does not make you faster than going forward
         camera_transform.translation += velocity.normalize_or_zero() * time.delta_seconds() * settings.speed;
 
-        if !(0.0 < camera_transform.translation.x
-            && camera_transform.translation.x < 100.0
-            && 0.0 < camera_transform.translation.z
-            && camera_transform.translation.z < 100.0)
+        if !(min.x < camera_transform.translation.x
+            && camera_transform.translation.x < max.x
+            && min.y < camera_transform.translation.z
+            && camera_transform.translation.z < max.y)
         {
             camera_transform.translation = initial_translation;
         }
@@ -178,7 +182,7 @@ fn toggle_camera_state(
     camera_state: Res<State<CameraState>>,
     construct_state: Res<State<ConstructPhase>>,
 ) {
-    if construct_state.0 == ConstructPhase::Preview {
+    if construct_state.0 != ConstructPhase::Normal {
         return;
     }
 
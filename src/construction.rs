@@ -0,0 +1,194 @@
+use std::time::Duration;
+
+use crate::*;
+
+// Size of the progress bar over a construction site, in world units
+const PROGRESS_BAR_WIDTH: f32 = 6.0;
+const PROGRESS_BAR_HEIGHT: f32 = 9.0;
+
+/// A placed building that isn't finished yet - it is hidden behind a crane and doesn't produce
+#[derive(Component)]
+pub struct UnderConstruction {
+    pub timer: Timer,
+}
+
+impl UnderConstruction {
+    pub fn new(build_time: f32, elapsed: f32) -> Self {
+        let mut timer = Timer::from_seconds(build_time, TimerMode::Once);
+        timer.set_elapsed(Duration::from_secs_f32(elapsed.clamp(0.0, build_time)));
+
+        Self { timer }
+    }
+
+    /// Gold Coins it takes to finish right away
+    pub fn rush_cost(&self, construct_data: &ConstructData) -> u32 {
+        (self.timer.remaining_secs() / construct_data.rush_seconds_per_gold).ceil() as u32
+    }
+
+    /// Finishes on the next tick
+    pub fn rush(&mut self) {
+        let duration = self.timer.duration();
+        self.timer.set_elapsed(duration);
+    }
+}
+
+/// The crane and progress bar standing in for a building while it is built
+#[derive(Component)]
+struct ConstructionSite {
+    building: Entity,
+}
+
+// Marker
+#[derive(Component)]
+struct ConstructionProgressFill;
+
+#[derive(Resource)]
+struct ConstructionAssets {
+    bar_mesh: Handle<Mesh>,
+    bar_material: Handle<StandardMaterial>,
+    fill_material: Handle<StandardMaterial>,
+}
+
+pub struct ConstructionPlugin;
+
+impl Plugin for ConstructionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(setup_construction_assets)
+            .add_systems((start_construction, spawn_construction_sites, tick_construction).chain());
+    }
+}
+
+fn setup_construction_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(ConstructionAssets {
+        bar_mesh: meshes.add(shape::Cube { size: 1.0 }.into()),
+        bar_material: materials.add(StandardMaterial {
+            base_color: Color::rgb(0.13, 0.14, 0.26),
+            unlit: true,
+            ..default()
+        }),
+        fill_material: materials.add(StandardMaterial {
+            base_color: Color::rgb(0.55, 0.44, 0.95),
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+/// Newly placed buildings have to be built before they produce anything
+fn start_construction(
+    mut commands: Commands,
+    mut game_events: EventReader<GameEvent>,
+    construct_data: Res<ConstructData>,
+) {
+    for event in game_events.iter() {
+        let GameEvent::Constructed {
+            entity, building_type, ..
+        } = event
+        else {
+            continue;
+        };
+
+        let build_time = construct_data.build_time(*building_type);
+
+        if build_time > 0.0 {
+            commands.entity(*entity).insert(UnderConstruction::new(build_time, 0.0));
+        }
+    }
+}
+
+/// Hides the building and puts a crane over its footprint instead
+fn spawn_construction_sites(
+    mut commands: Commands,
+    buildings: Query<(Entity, &Parent, &Building), Added<UnderConstruction>>,
+    models: Res<Models>,
+    construction_assets: Res<ConstructionAssets>,
+) {
+    for (entity, parent, building) in buildings.iter() {
+        commands.entity(entity).insert(Visibility::Hidden);
+
+        let (width, depth) = building.building_type.get_rotated_footprint(building.rotation);
+        let centre = Vec3::new(width as f32 - 1.0, 0.0, depth as f32 - 1.0) * TILE_SIZE / 2.0;
+        let mut crane_transform = BuildingType::Construct.get_transform();
+        crane_transform.scale *= 0.6;
+
+        let site = commands
+            .spawn(SpatialBundle::from_transform(Transform::from_translation(centre)))
+            .insert(Name::new("Construction site"))
+            .insert(ConstructionSite { building: entity })
+            .with_children(|commands| {
+                commands.spawn(SceneBundle {
+                    scene: models.construction_scene.clone(),
+                    transform: crane_transform,
+                    ..default()
+                });
+
+                commands.spawn(PbrBundle {
+                    mesh: construction_assets.bar_mesh.clone(),
+                    material: construction_assets.bar_material.clone(),
+                    transform: Transform::from_xyz(0.0, PROGRESS_BAR_HEIGHT, 0.0).with_scale(Vec3::new(
+                        PROGRESS_BAR_WIDTH,
+                        0.5,
+                        0.5,
+                    )),
+                    ..default()
+                });
+
+                commands
+                    .spawn(PbrBundle {
+                        mesh: construction_assets.bar_mesh.clone(),
+                        material: construction_assets.fill_material.clone(),
+                        transform: progress_fill_transform(0.0),
+                        ..default()
+                    })
+                    .insert(ConstructionProgressFill);
+            })
+            .id();
+
+        commands.entity(parent.get()).add_child(site);
+    }
+}
+
+/// The fill grows from the left end of the bar, and sticks out of it slightly so it's drawn on top
+fn progress_fill_transform(progress: f32) -> Transform {
+    let width = (PROGRESS_BAR_WIDTH * progress).max(0.01);
+
+    Transform::from_xyz((width - PROGRESS_BAR_WIDTH) / 2.0, PROGRESS_BAR_HEIGHT, 0.0)
+        .with_scale(Vec3::new(width, 0.6, 0.6))
+}
+
+fn tick_construction(
+    mut commands: Commands,
+    game_clock: Res<GameClock>,
+    mut buildings: Query<(Entity, &mut UnderConstruction)>,
+    sites: Query<(Entity, &ConstructionSite, &Children)>,
+    mut progress_fills: Query<&mut Transform, With<ConstructionProgressFill>>,
+) {
+    for (entity, mut under_construction) in buildings.iter_mut() {
+        under_construction.timer.tick(game_clock.delta());
+
+        if under_construction.timer.finished() {
+            commands
+                .entity(entity)
+                .remove::<UnderConstruction>()
+                .insert(Visibility::Inherited);
+        }
+    }
+
+    for (site_entity, site, children) in sites.iter() {
+        // Finished or demolished
+        let Ok((_, under_construction)) = buildings.get(site.building) else {
+            commands.entity(site_entity).despawn_recursive();
+            continue;
+        };
+
+        let mut progress_fills = progress_fills.iter_many_mut(children);
+
+        while let Some(mut transform) = progress_fills.fetch_next() {
+            *transform = progress_fill_transform(under_construction.timer.percent());
+        }
+    }
+}
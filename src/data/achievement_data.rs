@@ -0,0 +1,109 @@
+use crate::*;
+
+#[derive(Debug)]
+pub enum AchievementGoal {
+    /// Have at least this much money at once
+    Balance(u32),
+    /// Upgrade a building of this type to its highest level
+    MaxLevel(BuildingType),
+    /// Own this many buildings of a type at once
+    Own(BuildingType, u32),
+    Upgrades(u32),
+    ItemsCollected(u32),
+    ItemsSold(u32),
+    ItemsBought(u32),
+    Constructions(u32),
+}
+
+/// Permanent bonuses granted by an unlocked achievement, in percent
+#[derive(Debug, Clone, Copy)]
+pub enum AchievementBonus {
+    SellPrice(u32),
+    Yield(u32),
+}
+
+#[derive(Debug)]
+pub struct AchievementDefinition {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub goal: AchievementGoal,
+    pub bonus: Option<AchievementBonus>,
+}
+
+#[derive(Resource)]
+pub struct AchievementData {
+    pub achievements: Vec<AchievementDefinition>,
+}
+
+impl Default for AchievementData {
+    fn default() -> Self {
+        Self {
+            achievements: vec![
+                AchievementDefinition {
+                    id: "first_construction",
+                    name: "Breaking Ground",
+                    description: "Construct your first building",
+                    goal: AchievementGoal::Constructions(1),
+                    bonus: None,
+                },
+                AchievementDefinition {
+                    id: "first_upgrade",
+                    name: "Renovator",
+                    description: "Upgrade any building",
+                    goal: AchievementGoal::Upgrades(1),
+                    bonus: None,
+                },
+                AchievementDefinition {
+                    id: "own_candy_shops",
+                    name: "Sugar Rush",
+                    description: "Own 5 Candy Shops",
+                    goal: AchievementGoal::Own(BuildingType::CandyShop, 5),
+                    bonus: Some(AchievementBonus::Yield(5)),
+                },
+                AchievementDefinition {
+                    id: "max_factory",
+                    name: "Industrialist",
+                    description: "Max a Factory",
+                    goal: AchievementGoal::MaxLevel(BuildingType::Factory),
+                    bonus: Some(AchievementBonus::Yield(5)),
+                },
+                AchievementDefinition {
+                    id: "collect_items",
+                    name: "Hoarder",
+                    description: "Collect 1000 items from your buildings",
+                    goal: AchievementGoal::ItemsCollected(1000),
+                    bonus: None,
+                },
+                AchievementDefinition {
+                    id: "sell_items",
+                    name: "Merchant",
+                    description: "Sell 500 items",
+                    goal: AchievementGoal::ItemsSold(500),
+                    bonus: Some(AchievementBonus::SellPrice(5)),
+                },
+                AchievementDefinition {
+                    id: "buy_items",
+                    name: "Shopaholic",
+                    description: "Buy 100 items from the market",
+                    goal: AchievementGoal::ItemsBought(100),
+                    bonus: None,
+                },
+                AchievementDefinition {
+                    id: "balance_10k",
+                    name: "Pocket Money",
+                    description: "Reach $10k balance",
+                    goal: AchievementGoal::Balance(10_000),
+                    bonus: None,
+                },
+                AchievementDefinition {
+                    id: "balance_100k",
+                    name: "Six Figures",
+                    description: "Reach $100k balance",
+                    goal: AchievementGoal::Balance(100_000),
+                    bonus: Some(AchievementBonus::SellPrice(5)),
+                },
+            ],
+        }
+    }
+}
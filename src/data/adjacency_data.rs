@@ -0,0 +1,132 @@
+use crate::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdjacencyBonus {
+    /// Extra production of one item, in percent
+    Yield(ItemType, u32),
+    /// Faster production cycles, in percent
+    Speed(f32),
+}
+
+#[derive(Debug)]
+pub struct AdjacencyRule {
+    pub building_type: BuildingType,
+    /// Applies when any building of this type shares an edge with the building
+    pub neighbour: BuildingType,
+    pub bonus: AdjacencyBonus,
+    pub description: &'static str,
+}
+
+/// Bonuses buildings get from their neighbours on the grid
+#[derive(Resource)]
+pub struct AdjacencyData {
+    pub rules: Vec<AdjacencyRule>,
+    /// Attractiveness each decoration spreads to the buildings around it
+    pub attractiveness: Vec<(BuildingType, u32)>,
+    /// How many tiles away a decoration still counts
+    pub attraction_radius: u8,
+    /// Buildings that benefit from attractiveness
+    pub attracted: Vec<BuildingType>,
+    /// Faster production cycles per point of attractiveness, in percent
+    pub speed_per_attractiveness: f32,
+    /// Cap on the speed bonus from attractiveness, in percent
+    pub max_attraction_bonus: f32,
+}
+
+impl AdjacencyData {
+    /// Total attractiveness from decorations within reach of a footprint placed on `corner`
+    pub fn attractiveness_at(
+        &self,
+        building_type: BuildingType,
+        decorations: impl Iterator<Item = ((u8, u8), BuildingType)>,
+        (i, j): (u8, u8),
+        (width, depth): (u8, u8),
+    ) -> u32 {
+        if !self.attracted.contains(&building_type) {
+            return 0;
+        }
+
+        decorations
+            .filter(|((x, z), _)| {
+                // Distance to the nearest tile of the footprint
+                let distance_x = i.saturating_sub(*x).max(x.saturating_sub(i + width - 1));
+                let distance_z = j.saturating_sub(*z).max(z.saturating_sub(j + depth - 1));
+
+                distance_x.max(distance_z) <= self.attraction_radius
+            })
+            .filter_map(|(_, decoration)| {
+                self.attractiveness
+                    .iter()
+                    .find(|(decoration_type, _)| *decoration_type == decoration)
+                    .map(|(_, attractiveness)| attractiveness)
+            })
+            .sum()
+    }
+
+    /// The rules that apply to a building with these neighbours and this much attractiveness around it
+    pub fn synergies(
+        &self,
+        building_type: BuildingType,
+        neighbours: &[BuildingType],
+        attractiveness: u32,
+    ) -> Synergies {
+        let mut synergies = Synergies {
+            attractiveness,
+            speed_bonus: (attractiveness as f32 * self.speed_per_attractiveness).min(self.max_attraction_bonus),
+            ..default()
+        };
+
+        for (index, rule) in self.rules.iter().enumerate() {
+            if rule.building_type != building_type || !neighbours.contains(&rule.neighbour) {
+                continue;
+            }
+
+            synergies.rules.push(index);
+
+            match rule.bonus {
+                AdjacencyBonus::Yield(item_type, percent) => synergies.yield_bonus.push((item_type, percent)),
+                AdjacencyBonus::Speed(percent) => synergies.speed_bonus += percent,
+            }
+        }
+
+        synergies
+    }
+}
+
+impl Default for AdjacencyData {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                AdjacencyRule {
+                    building_type: BuildingType::Cabin,
+                    neighbour: BuildingType::Tree,
+                    bonus: AdjacencyBonus::Yield(ItemType::Log, 20),
+                    description: "Next to a Big Tree: +20% Log",
+                },
+                AdjacencyRule {
+                    building_type: BuildingType::CoffeeShop,
+                    neighbour: BuildingType::CandyShop,
+                    bonus: AdjacencyBonus::Speed(25.0),
+                    description: "Next to a Candy Shop: 25% faster",
+                },
+                AdjacencyRule {
+                    building_type: BuildingType::CandyShop,
+                    neighbour: BuildingType::CoffeeShop,
+                    bonus: AdjacencyBonus::Yield(ItemType::Taffy, 20),
+                    description: "Next to a Café: +20% Taffy",
+                },
+                AdjacencyRule {
+                    building_type: BuildingType::Factory,
+                    neighbour: BuildingType::Cabin,
+                    bonus: AdjacencyBonus::Speed(15.0),
+                    description: "Next to a Cabin: 15% faster",
+                },
+            ],
+            attractiveness: vec![(BuildingType::Flowerbed, 2)],
+            attraction_radius: 2,
+            attracted: vec![BuildingType::CandyShop, BuildingType::CoffeeShop],
+            speed_per_attractiveness: 5.0,
+            max_attraction_bonus: 40.0,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::*;
+
+#[derive(Debug, Clone, Copy)]
+pub struct LoanOffer {
+    pub amount: u32,
+    /// Interest charged on top of the amount, in percent
+    pub interest: u32,
+    /// Number of repayments the loan is split into
+    pub installments: u32,
+}
+
+/// Terms offered by the bank in the City Centre
+#[derive(Resource)]
+pub struct BankData {
+    /// Seconds between savings interest payouts and between loan repayments
+    pub period: f32,
+    /// Interest paid on savings every period, in percent
+    pub savings_interest: u32,
+    /// Added to the outstanding debt for every missed repayment, in percent of the installment
+    pub late_fee: u32,
+    /// Missed repayments in a row before the loan defaults
+    pub max_missed_payments: u32,
+    /// Extra interest on every future loan for each default, in percent
+    pub default_interest_penalty: u32,
+    pub loan_offers: Vec<LoanOffer>,
+}
+
+impl Default for BankData {
+    fn default() -> Self {
+        Self {
+            period: 60.0,
+            savings_interest: 1,
+            late_fee: 25,
+            max_missed_payments: 3,
+            default_interest_penalty: 5,
+            loan_offers: vec![
+                LoanOffer {
+                    amount: 1_000,
+                    interest: 5,
+                    installments: 5,
+                },
+                LoanOffer {
+                    amount: 10_000,
+                    interest: 10,
+                    installments: 10,
+                },
+                LoanOffer {
+                    amount: 50_000,
+                    interest: 20,
+                    installments: 20,
+                },
+            ],
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::*;
+
+#[derive(Debug)]
+pub struct CityEventDefinition {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Seconds the event lasts for
+    pub duration: f32,
+    pub modifiers: Vec<Modifier>,
+}
+
+/// Timed city-wide events that are rolled at random
+#[derive(Resource)]
+pub struct CityEventData {
+    /// Seconds between rolls
+    pub interval: f32,
+    /// Chance of an event starting on each roll, in percent
+    pub chance: u32,
+    pub events: Vec<CityEventDefinition>,
+}
+
+impl Default for CityEventData {
+    fn default() -> Self {
+        Self {
+            interval: 90.0,
+            chance: 40,
+            events: vec![
+                CityEventDefinition {
+                    name: "Candy Festival",
+                    description: "Candy Shops produce double",
+                    duration: 120.0,
+                    modifiers: vec![Modifier::Yield(BuildingType::CandyShop, 200)],
+                },
+                CityEventDefinition {
+                    name: "Steel Shortage",
+                    description: "The market is out of Steel",
+                    duration: 180.0,
+                    modifiers: vec![
+                        Modifier::Shortage(ItemType::Steel),
+                        Modifier::BuyPrice(ItemType::Steel, 150),
+                    ],
+                },
+                CityEventDefinition {
+                    name: "Storm",
+                    description: "Trees have stopped producing",
+                    duration: 90.0,
+                    modifiers: vec![Modifier::Yield(BuildingType::Tree, 0)],
+                },
+                CityEventDefinition {
+                    name: "Coffee Craze",
+                    description: "Coffee sells for 50% more",
+                    duration: 120.0,
+                    modifiers: vec![Modifier::SellPrice(ItemType::Coffee, 150)],
+                },
+            ],
+        }
+    }
+}
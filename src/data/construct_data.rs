@@ -0,0 +1,119 @@
+use crate::*;
+use std::collections::HashMap;
+
+/// How one building type is stocked at the Construct and how long it takes to build
+pub struct StockRule {
+    /// City Centre level the building type appears at
+    pub unlock_level: u8,
+    /// Most the Construct holds once unlocked
+    pub base_cap: u8,
+    /// Extra room for every City Centre level past the unlock
+    pub cap_per_level: u8,
+    /// Seconds it takes to build once placed
+    pub build_time: f32,
+}
+
+/// Stock levels, unlocks and build times of the Construct shop
+#[derive(Resource)]
+pub struct ConstructData {
+    /// Seconds between restocks - every unlocked building type below its cap gets one more
+    pub restock_period: f32,
+    pub stock: HashMap<BuildingType, StockRule>,
+    /// Seconds of construction one Gold Coin skips
+    pub rush_seconds_per_gold: f32,
+}
+
+impl ConstructData {
+    pub fn unlock_level(&self, building_type: BuildingType) -> u8 {
+        self.stock
+            .get(&building_type)
+            .map_or(u8::MAX, |stock_rule| stock_rule.unlock_level)
+    }
+
+    pub fn is_unlocked(&self, building_type: BuildingType, city_level: u8) -> bool {
+        city_level >= self.unlock_level(building_type)
+    }
+
+    pub fn build_time(&self, building_type: BuildingType) -> f32 {
+        self.stock
+            .get(&building_type)
+            .map_or(0.0, |stock_rule| stock_rule.build_time)
+    }
+
+    /// Most of a building type the Construct holds at this City Centre level - 0 while locked
+    pub fn cap(&self, building_type: BuildingType, city_level: u8) -> u8 {
+        match self.stock.get(&building_type) {
+            Some(stock_rule) if city_level >= stock_rule.unlock_level => {
+                stock_rule.base_cap + stock_rule.cap_per_level * (city_level - stock_rule.unlock_level)
+            }
+            _ => 0,
+        }
+    }
+}
+
+impl Default for ConstructData {
+    fn default() -> Self {
+        let mut stock = HashMap::new();
+
+        stock.insert(
+            BuildingType::CandyShop,
+            StockRule {
+                unlock_level: 1,
+                base_cap: 4,
+                cap_per_level: 1,
+                build_time: 20.0,
+            },
+        );
+        stock.insert(
+            BuildingType::CoffeeShop,
+            StockRule {
+                unlock_level: 1,
+                base_cap: 3,
+                cap_per_level: 1,
+                build_time: 40.0,
+            },
+        );
+        stock.insert(
+            BuildingType::Tree,
+            StockRule {
+                unlock_level: 1,
+                base_cap: 3,
+                cap_per_level: 1,
+                build_time: 30.0,
+            },
+        );
+        stock.insert(
+            BuildingType::Factory,
+            StockRule {
+                unlock_level: 2,
+                base_cap: 2,
+                cap_per_level: 1,
+                build_time: 90.0,
+            },
+        );
+        stock.insert(
+            BuildingType::Cabin,
+            StockRule {
+                unlock_level: 2,
+                base_cap: 2,
+                cap_per_level: 1,
+                build_time: 60.0,
+            },
+        );
+        stock.insert(
+            BuildingType::Flowerbed,
+            StockRule {
+                unlock_level: 1,
+                base_cap: 6,
+                cap_per_level: 2,
+                build_time: 5.0,
+            },
+        );
+
+        Self {
+            restock_period: 60.0,
+            stock,
+            rush_seconds_per_gold: 10.0,
+        }
+    }
+}
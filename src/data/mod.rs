@@ -1,3 +1,19 @@
+mod achievement_data;
+mod adjacency_data;
+mod bank_data;
+mod city_event_data;
+mod construct_data;
+mod quest_data;
+mod terrain_data;
 mod upgrade_data;
+mod upkeep_data;
 
+pub use achievement_data::*;
+pub use adjacency_data::*;
+pub use bank_data::*;
+pub use city_event_data::*;
+pub use construct_data::*;
+pub use quest_data::*;
+pub use terrain_data::*;
 pub use upgrade_data::*;
+pub use upkeep_data::*;
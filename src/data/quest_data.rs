@@ -0,0 +1,155 @@
+use crate::*;
+
+#[derive(Debug)]
+pub enum QuestGoal {
+    /// Place this many new buildings of a type
+    Construct(BuildingType, u32),
+    /// Collect this many of an item from buildings
+    Collect(ItemType, u32),
+    /// Upgrade a building of this type once
+    Upgrade(BuildingType),
+    /// Sell this many items of any type
+    Sell(u32),
+    /// Sell this many items while they are boosted
+    SellBoosted(u32),
+    /// Buy this many items from the market
+    Buy(u32),
+    /// Have at least this much money at once
+    Balance(u32),
+}
+
+impl QuestGoal {
+    pub fn target(&self) -> u32 {
+        use QuestGoal::*;
+        match self {
+            Construct(_, target) => *target,
+            Collect(_, target) => *target,
+            Upgrade(_) => 1,
+            Sell(target) => *target,
+            SellBoosted(target) => *target,
+            Buy(target) => *target,
+            Balance(target) => *target,
+        }
+    }
+
+    /// How much an event counts towards this goal
+    pub fn progress_from(&self, event: &GameEvent) -> u32 {
+        match (self, event) {
+            (QuestGoal::Construct(goal_type, _), GameEvent::Constructed { building_type, .. })
+                if goal_type == building_type =>
+            {
+                1
+            }
+            (QuestGoal::Collect(goal_type, _), GameEvent::Collected { items, .. }) => items
+                .iter()
+                .filter(|(item_type, _)| item_type == goal_type)
+                .map(|(_, quantity)| quantity)
+                .sum(),
+            (QuestGoal::Upgrade(goal_type), GameEvent::Upgraded { building_type, .. })
+                if goal_type == building_type =>
+            {
+                1
+            }
+            (QuestGoal::Sell(_), GameEvent::Sold { quantity, .. }) => *quantity,
+            (
+                QuestGoal::SellBoosted(_),
+                GameEvent::Sold {
+                    quantity,
+                    boosted: true,
+                    ..
+                },
+            ) => *quantity,
+            (QuestGoal::Buy(_), GameEvent::Bought { quantity, .. }) => *quantity,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum QuestReward {
+    Cash(u32),
+    Item(ItemType, u32),
+}
+
+#[derive(Debug)]
+pub struct QuestDefinition {
+    pub title: &'static str,
+    pub description: &'static str,
+    pub goal: QuestGoal,
+    pub rewards: Vec<QuestReward>,
+}
+
+/// The quest chain, in the order the player works through it
+#[derive(Resource)]
+pub struct QuestData {
+    pub quests: Vec<QuestDefinition>,
+}
+
+impl Default for QuestData {
+    fn default() -> Self {
+        Self {
+            quests: vec![
+                QuestDefinition {
+                    title: "Open for Business",
+                    description: "Buy a Candy Shop from the Construct and place it",
+                    goal: QuestGoal::Construct(BuildingType::CandyShop, 1),
+                    rewards: vec![QuestReward::Cash(100)],
+                },
+                QuestDefinition {
+                    title: "Sweet Tooth",
+                    description: "Collect 100 Taffy from your Candy Shops",
+                    goal: QuestGoal::Collect(ItemType::Taffy, 100),
+                    rewards: vec![QuestReward::Cash(250)],
+                },
+                QuestDefinition {
+                    title: "Market Day",
+                    description: "Sell 50 items from your inventory",
+                    goal: QuestGoal::Sell(50),
+                    rewards: vec![QuestReward::Item(ItemType::Nougat, 10)],
+                },
+                QuestDefinition {
+                    title: "Ride the Wave",
+                    description: "Sell 10 items while they are boosted",
+                    goal: QuestGoal::SellBoosted(10),
+                    rewards: vec![QuestReward::Cash(500)],
+                },
+                QuestDefinition {
+                    title: "Growing Pains",
+                    description: "Upgrade a Candy Shop",
+                    goal: QuestGoal::Upgrade(BuildingType::CandyShop),
+                    rewards: vec![QuestReward::Item(ItemType::Steel, 5), QuestReward::Cash(300)],
+                },
+                QuestDefinition {
+                    title: "Wake Up and Smell the Coffee",
+                    description: "Place a Coffee Shop",
+                    goal: QuestGoal::Construct(BuildingType::CoffeeShop, 1),
+                    rewards: vec![QuestReward::Item(ItemType::Coffee, 30)],
+                },
+                QuestDefinition {
+                    title: "Window Shopping",
+                    description: "Buy 20 items from the Market",
+                    goal: QuestGoal::Buy(20),
+                    rewards: vec![QuestReward::Item(ItemType::Branch, 10)],
+                },
+                QuestDefinition {
+                    title: "Civic Pride",
+                    description: "Upgrade the City Centre",
+                    goal: QuestGoal::Upgrade(BuildingType::CityCentre),
+                    rewards: vec![QuestReward::Item(ItemType::GoldCoin, 5), QuestReward::Cash(1000)],
+                },
+                QuestDefinition {
+                    title: "Heavy Industry",
+                    description: "Place a Factory",
+                    goal: QuestGoal::Construct(BuildingType::Factory, 1),
+                    rewards: vec![QuestReward::Item(ItemType::Chip, 10)],
+                },
+                QuestDefinition {
+                    title: "Tycoon",
+                    description: "Reach a balance of $25k",
+                    goal: QuestGoal::Balance(25_000),
+                    rewards: vec![QuestReward::Item(ItemType::GoldCoin, 20)],
+                },
+            ],
+        }
+    }
+}
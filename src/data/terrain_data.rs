@@ -0,0 +1,71 @@
+use crate::*;
+
+#[derive(Debug)]
+pub struct TerrainEffect {
+    pub terrain_type: TerrainType,
+    pub building_type: BuildingType,
+    pub bonus: AdjacencyBonus,
+    pub description: &'static str,
+}
+
+/// Bonuses buildings get from the ground they stand on
+#[derive(Resource)]
+pub struct TerrainData {
+    pub effects: Vec<TerrainEffect>,
+}
+
+impl TerrainData {
+    /// Adds the effects of the terrain under most of a building's footprint
+    pub fn apply(&self, synergies: &mut Synergies, building_type: BuildingType, terrain: &[TerrainType]) {
+        for (index, effect) in self.effects.iter().enumerate() {
+            let covered = terrain
+                .iter()
+                .filter(|terrain_type| **terrain_type == effect.terrain_type)
+                .count();
+
+            if effect.building_type != building_type || covered == 0 || covered * 2 < terrain.len() {
+                continue;
+            }
+
+            synergies.terrain.push(index);
+
+            match effect.bonus {
+                AdjacencyBonus::Yield(item_type, percent) => synergies.yield_bonus.push((item_type, percent)),
+                AdjacencyBonus::Speed(percent) => synergies.speed_bonus += percent,
+            }
+        }
+    }
+}
+
+impl Default for TerrainData {
+    fn default() -> Self {
+        Self {
+            effects: vec![
+                TerrainEffect {
+                    terrain_type: TerrainType::Fertile,
+                    building_type: BuildingType::Tree,
+                    bonus: AdjacencyBonus::Yield(ItemType::Apple, 25),
+                    description: "Fertile soil: +25% Apple",
+                },
+                TerrainEffect {
+                    terrain_type: TerrainType::Fertile,
+                    building_type: BuildingType::Tree,
+                    bonus: AdjacencyBonus::Yield(ItemType::Branch, 25),
+                    description: "Fertile soil: +25% Branch",
+                },
+                TerrainEffect {
+                    terrain_type: TerrainType::Forest,
+                    building_type: BuildingType::Cabin,
+                    bonus: AdjacencyBonus::Yield(ItemType::Log, 25),
+                    description: "In the forest: +25% Log",
+                },
+                TerrainEffect {
+                    terrain_type: TerrainType::Rock,
+                    building_type: BuildingType::Factory,
+                    bonus: AdjacencyBonus::Speed(30.0),
+                    description: "On rock: 30% faster",
+                },
+            ],
+        }
+    }
+}
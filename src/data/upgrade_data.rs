@@ -1,9 +1,13 @@
 use crate::*;
 use std::collections::HashMap;
 
+// Uncollected production cycles a building holds before its storage counts as full
+pub const STORAGE_CYCLES: u32 = 10;
+
 #[derive(Debug)]
 pub struct LevelStats {
-    pub speed: u8,
+    // Seconds per production cycle
+    pub speed: f32,
     pub yields: [(ItemType, u8); 3],
     pub upgrade_materials: [(ItemType, u32); 3],
     pub upgrade_cost: u32,
@@ -14,6 +18,59 @@ pub struct UpgradeData {
     pub map: HashMap<BuildingType, HashMap<u8, LevelStats>>,
 }
 
+impl UpgradeData {
+    /// None for buildings that don't level up, like decorations
+    pub fn level_stats(&self, building_type: BuildingType, level: u8) -> Option<&LevelStats> {
+        self.map.get(&building_type).and_then(|stats_map| stats_map.get(&level))
+    }
+
+    /// Whether the inventory covers the money and materials for a building's next level
+    pub fn can_afford_upgrade(&self, building_type: BuildingType, level: u8, inventory: &Inventory) -> bool {
+        let (Some(level_stats), Some(_)) = (
+            self.level_stats(building_type, level),
+            self.level_stats(building_type, level + 1),
+        ) else {
+            return false;
+        };
+
+        inventory.balance >= level_stats.upgrade_cost
+            && level_stats.upgrade_materials.iter().all(|(item_type, required)| {
+                inventory
+                    .items
+                    .iter()
+                    .any(|item| item.item_type == *item_type && item.quantity >= *required)
+            })
+    }
+
+    /// A building is full once any of its yields holds STORAGE_CYCLES worth of production
+    pub fn storage_full(&self, building: &Building) -> bool {
+        let Some(level_stats) = self.level_stats(building.building_type, building.level) else {
+            return false;
+        };
+
+        building.yields.iter().any(|(item_type, stored)| {
+            level_stats.yields.iter().any(|(yield_type, per_cycle)| {
+                yield_type == item_type && *per_cycle > 0 && *stored >= *per_cycle as u32 * STORAGE_CYCLES
+            })
+        })
+    }
+
+    /// Rough value of everything a building produces per minute, if it were all sold at base price
+    pub fn income_per_minute(&self, building_type: BuildingType, level: u8) -> f32 {
+        let Some(level_stats) = self.map.get(&building_type).and_then(|stats_map| stats_map.get(&level)) else {
+            return 0.0;
+        };
+
+        let value_per_cycle = level_stats
+            .yields
+            .iter()
+            .map(|(item_type, quantity)| Item::get_sell_price(*item_type) * *quantity as u32)
+            .sum::<u32>();
+
+        value_per_cycle as f32 * 60.0 / level_stats.speed
+    }
+}
+
 impl Default for UpgradeData {
     fn default() -> Self {
         let mut map = HashMap::new();
@@ -23,7 +80,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 1,
                 LevelStats {
-                    speed: 15,
+                    speed: 15.0,
                     yields: [
                         (ItemType::BronzeCoin, 10),
                         (ItemType::SilverCoin, 0),
@@ -37,7 +94,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 2,
                 LevelStats {
-                    speed: 12,
+                    speed: 12.0,
                     yields: [
                         (ItemType::BronzeCoin, 15),
                         (ItemType::SilverCoin, 3),
@@ -56,7 +113,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 1,
                 LevelStats {
-                    speed: 15,
+                    speed: 15.0,
                     yields: [(ItemType::Taffy, 10), (ItemType::Nougat, 1), (ItemType::Marshmallow, 0)],
                     upgrade_materials: [(ItemType::Taffy, 60), (ItemType::Nougat, 10), (ItemType::Steel, 5)],
                     upgrade_cost: 800,
@@ -66,7 +123,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 2,
                 LevelStats {
-                    speed: 12,
+                    speed: 12.0,
                     yields: [(ItemType::Taffy, 18), (ItemType::Nougat, 6), (ItemType::Marshmallow, 0)],
                     upgrade_materials: [
                         (ItemType::Nougat, 30),
@@ -85,7 +142,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 1,
                 LevelStats {
-                    speed: 15,
+                    speed: 15.0,
                     yields: [(ItemType::Coffee, 10), (ItemType::Cocoa, 2), (ItemType::Milkshake, 0)],
                     upgrade_materials: [(ItemType::Coffee, 60), (ItemType::Cocoa, 20), (ItemType::Branch, 5)],
                     upgrade_cost: 1000,
@@ -95,7 +152,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 2,
                 LevelStats {
-                    speed: 12,
+                    speed: 12.0,
                     yields: [(ItemType::Coffee, 20), (ItemType::Cocoa, 5), (ItemType::Milkshake, 1)],
                     upgrade_materials: [
                         (ItemType::Cocoa, 35),
@@ -114,7 +171,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 1,
                 LevelStats {
-                    speed: 15,
+                    speed: 15.0,
                     yields: [(ItemType::Apple, 8), (ItemType::Branch, 2), (ItemType::Honey, 0)],
                     upgrade_materials: [
                         (ItemType::Apple, 60),
@@ -128,7 +185,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 2,
                 LevelStats {
-                    speed: 12,
+                    speed: 12.0,
                     yields: [(ItemType::Apple, 20), (ItemType::Branch, 6), (ItemType::Honey, 1)],
                     upgrade_materials: [
                         (ItemType::Branch, 40),
@@ -147,7 +204,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 1,
                 LevelStats {
-                    speed: 15,
+                    speed: 15.0,
                     yields: [(ItemType::Steel, 6), (ItemType::Chip, 1), (ItemType::Phone, 0)],
                     upgrade_materials: [(ItemType::Steel, 36), (ItemType::Chip, 10), (ItemType::Log, 20)],
                     upgrade_cost: 2500,
@@ -157,7 +214,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 2,
                 LevelStats {
-                    speed: 12,
+                    speed: 12.0,
                     yields: [(ItemType::Steel, 14), (ItemType::Chip, 3), (ItemType::Phone, 0)],
                     upgrade_materials: [(ItemType::Steel, 120), (ItemType::Phone, 5), (ItemType::Log, 50)],
                     upgrade_cost: 5600,
@@ -172,7 +229,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 1,
                 LevelStats {
-                    speed: 15,
+                    speed: 15.0,
                     yields: [(ItemType::Log, 6), (ItemType::Lantern, 2), (ItemType::Axe, 0)],
                     upgrade_materials: [(ItemType::Log, 36), (ItemType::BronzeCoin, 35), (ItemType::Branch, 50)],
                     upgrade_cost: 2400,
@@ -182,7 +239,7 @@ impl Default for UpgradeData {
             stats_map.insert(
                 2,
                 LevelStats {
-                    speed: 12,
+                    speed: 12.0,
                     yields: [(ItemType::Log, 14), (ItemType::Lantern, 5), (ItemType::Axe, 0)],
                     upgrade_materials: [(ItemType::Log, 130), (ItemType::SilverCoin, 30), (ItemType::Axe, 5)],
                     upgrade_cost: 5300,
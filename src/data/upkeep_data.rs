@@ -0,0 +1,41 @@
+use crate::*;
+use std::collections::HashMap;
+
+/// Running costs of buildings and the taxes the city collects in return
+#[derive(Resource)]
+pub struct UpkeepData {
+    /// Seconds between bills
+    pub period: f32,
+    /// Upkeep per level of a building, charged every period
+    pub upkeep: HashMap<BuildingType, u32>,
+    /// Taxes per level of every building, collected every period
+    pub tax_per_level: u32,
+    /// Share of normal production while upkeep is unpaid, in percent
+    pub unpaid_production: u32,
+}
+
+impl UpkeepData {
+    pub fn upkeep(&self, building_type: BuildingType, level: u8) -> u32 {
+        self.upkeep.get(&building_type).copied().unwrap_or_default() * level as u32
+    }
+}
+
+impl Default for UpkeepData {
+    fn default() -> Self {
+        let mut upkeep = HashMap::new();
+
+        upkeep.insert(BuildingType::CityCentre, 0);
+        upkeep.insert(BuildingType::CandyShop, 12);
+        upkeep.insert(BuildingType::CoffeeShop, 15);
+        upkeep.insert(BuildingType::Tree, 5);
+        upkeep.insert(BuildingType::Factory, 30);
+        upkeep.insert(BuildingType::Cabin, 10);
+
+        Self {
+            period: 60.0,
+            upkeep,
+            tax_per_level: 5,
+            unpaid_production: 50,
+        }
+    }
+}
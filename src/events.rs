@@ -0,0 +1,46 @@
+use crate::*;
+
+/// Sent whenever the player does something with the economy - anything that wants to keep track of
+/// the player's progress (achievements, quests, etc) should listen for these rather than poking at
+/// the UI systems directly
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    Upgraded {
+        building_type: BuildingType,
+        level: u8,
+        cost: u32,
+    },
+    /// A building's production timer finished and added to its yields
+    Produced {
+        entity: Entity,
+        building_type: BuildingType,
+        items: Vec<(ItemType, u32)>,
+    },
+    Collected {
+        building_type: BuildingType,
+        items: Vec<(ItemType, u32)>,
+    },
+    Sold {
+        item_type: ItemType,
+        quantity: u32,
+        revenue: u32,
+        boosted: bool,
+    },
+    Bought {
+        item_type: ItemType,
+        quantity: u32,
+        cost: u32,
+    },
+    Constructed {
+        entity: Entity,
+        building_type: BuildingType,
+        cost: u32,
+    },
+    LandBought {
+        cost: u32,
+    },
+    Demolished {
+        building_type: BuildingType,
+        refund: u32,
+    },
+}
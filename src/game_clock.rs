@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use bevy::window::PrimaryWindow;
+
+use crate::*;
+
+/// Multipliers the game clock can run at, paused first
+pub const GAME_SPEEDS: [f32; 5] = [0.0, 1.0, 2.0, 5.0, 10.0];
+
+/// Time as the economy sees it - production, restocks, upkeep, events and saves all run on this
+/// instead of `Time`, so they can be paused or fast-forwarded while the UI and camera keep real time
+#[derive(Resource)]
+pub struct GameClock {
+    // Index into GAME_SPEEDS
+    speed_index: usize,
+    // Speed to go back to when unpausing
+    resume_index: usize,
+    delta: Duration,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self {
+            speed_index: 1,
+            resume_index: 1,
+            delta: Duration::ZERO,
+        }
+    }
+}
+
+impl GameClock {
+    /// Game time passed since the last frame
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    pub fn speed(&self) -> f32 {
+        GAME_SPEEDS[self.speed_index]
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.speed_index == 0
+    }
+
+    pub fn toggle_pause(&mut self) {
+        if self.is_paused() {
+            self.speed_index = self.resume_index;
+        } else {
+            self.resume_index = self.speed_index;
+            self.speed_index = 0;
+        }
+    }
+
+    /// Steps to the next faster speed, wrapping back round to 1x - unpauses too
+    pub fn speed_up(&mut self) {
+        self.speed_index = if self.speed_index + 1 < GAME_SPEEDS.len() {
+            self.speed_index + 1
+        } else {
+            1
+        };
+        self.resume_index = self.speed_index;
+    }
+
+    /// What the on-screen indicator shows
+    pub fn label(&self) -> String {
+        if self.is_paused() {
+            "Paused".to_string()
+        } else {
+            format!("Speed {}x", self.speed())
+        }
+    }
+}
+
+pub struct GameClockPlugin;
+
+impl Plugin for GameClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameClock>()
+            .add_system(advance_game_clock.in_base_set(CoreSet::PreUpdate))
+            .add_startup_system(draw_game_speed_indicator)
+            .add_systems((change_game_speed, update_game_speed_indicator).chain());
+    }
+}
+
+pub fn advance_game_clock(time: Res<Time>, mut game_clock: ResMut<GameClock>) {
+    game_clock.delta = time.delta().mul_f32(game_clock.speed());
+}
+
+fn change_game_speed(keybinds: Res<Keybinds>, keys: Res<Input<KeyCode>>, mut game_clock: ResMut<GameClock>) {
+    if keys.just_pressed(keybinds.toggle_pause) {
+        game_clock.toggle_pause();
+    }
+
+    if keys.just_pressed(keybinds.change_game_speed) {
+        game_clock.speed_up();
+    }
+}
+
+// Marker
+#[derive(Component)]
+struct GameSpeedText;
+
+fn draw_game_speed_indicator(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    game_clock: Res<GameClock>,
+) {
+    let indicator_height = primary_window.single().resolution.height() / 25.0;
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Auto, Val::Px(indicator_height)),
+                align_items: AlignItems::Center,
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    right: Val::Percent(1.0),
+                    bottom: Val::Percent(2.0),
+                    ..default()
+                },
+                padding: UiRect::horizontal(Val::Px(indicator_height / 3.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.17, 0.19, 0.36, 0.85).into(),
+            ..default()
+        })
+        .insert(Name::new("Game speed"))
+        .with_children(|commands| {
+            commands
+                .spawn(TextBundle::from_section(
+                    game_clock.label(),
+                    TextStyle {
+                        font: asset_server.load("font.otf"),
+                        font_size: indicator_height / 2.0,
+                        color: Color::WHITE,
+                    },
+                ))
+                .insert(GameSpeedText);
+        });
+}
+
+fn update_game_speed_indicator(game_clock: Res<GameClock>, mut texts: Query<&mut Text, With<GameSpeedText>>) {
+    let label = game_clock.label();
+    let colour = if game_clock.is_paused() {
+        Color::rgb(0.95, 0.35, 0.35)
+    } else {
+        Color::WHITE
+    };
+
+    for mut text in texts.iter_mut() {
+        if text.sections[0].value != label {
+            text.sections[0].value = label.clone();
+            text.sections[0].style.color = colour;
+        }
+    }
+}
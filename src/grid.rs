@@ -1,10 +1,19 @@
+use bevy::utils::HashMap;
 use bevy_inspector_egui::quick::StateInspectorPlugin;
 use bevy_mod_picking::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::{f32::consts::PI, time::Duration};
 
 use crate::*;
 
-const TILE_SIZE: f32 = 10.0;
+pub const TILE_SIZE: f32 = 10.0;
+// Tiles along each side of the map
+pub const GRID_SIZE: u8 = 11;
+
+// Where the buildings every city starts with go
+pub const CITY_CENTRE_COORDS: (u8, u8) = (7, 6);
+pub const MARKET_COORDS: (u8, u8) = (7, 7);
+pub const CONSTRUCT_COORDS: (u8, u8) = (8, 4);
 
 pub struct GridPlugin;
 
@@ -12,13 +21,18 @@ impl Plugin for GridPlugin {
     fn build(&self, app: &mut App) {
         app.add_state::<SetupState>()
             .add_event::<UpgradeTarget>()
+            .add_event::<TileClickEvent>()
             .register_type::<SetupState>()
             // .add_plugin(StateInspectorPlugin::<SetupState>::default())
             .register_type::<Tile>()
             .register_type::<PickSelection>()
             .register_type::<Building>()
+            .init_resource::<GridIndex>()
+            .init_resource::<Terrain>()
             .add_startup_system(spawn_grid)
-            .add_system(setup_buildings.run_if(state_exists_and_equals(SetupState::SpawnTileDone)));
+            .add_system(setup_buildings.run_if(state_exists_and_equals(SetupState::SpawnTileDone)))
+            .add_systems((open_clicked_tile, place_building, tint_preview_ghost, update_occupancy))
+            .add_system(rotate_preview.in_set(OnUpdate(ConstructPhase::Preview)));
     }
 }
 
@@ -32,19 +46,174 @@ pub enum SetupState {
 
 #[derive(Component, Reflect, Debug)]
 pub struct Tile {
-    // pub building: Option<Building>,
-    pub x: f32,
-    pub z: f32,
+    // Position in the grid, rather than in the world
+    pub i: u8,
+    pub j: u8,
 }
 
 impl Tile {
-    fn new(x: f32, z: f32) -> Self {
-        // Self { building: None, x, z }
-        Self { x, z }
+    fn new(i: u8, j: u8) -> Self {
+        Self { i, j }
+    }
+
+    pub fn coords(&self) -> (u8, u8) {
+        (self.i, self.j)
+    }
+
+    /// World space x and z of the tile's centre
+    pub fn position(&self) -> (f32, f32) {
+        (self.i as f32 * TILE_SIZE, self.j as f32 * TILE_SIZE)
+    }
+}
+
+/// The four coordinates sharing an edge with these ones - they may be off the grid
+pub fn edge_neighbours((i, j): (u8, u8)) -> impl Iterator<Item = (u8, u8)> {
+    [
+        i.checked_sub(1).map(|i| (i, j)),
+        i.checked_add(1).map(|i| (i, j)),
+        j.checked_sub(1).map(|j| (i, j)),
+        j.checked_add(1).map(|j| (i, j)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Finds tiles and the buildings covering them by grid coordinates
+#[derive(Resource, Default)]
+pub struct GridIndex {
+    tiles: HashMap<(u8, u8), Entity>,
+    // Buildings are parented to the corner tile of their footprint, but listed under every tile it covers
+    occupants: HashMap<(u8, u8), Entity>,
+    terrain: HashMap<(u8, u8), TerrainType>,
+}
+
+impl GridIndex {
+    pub fn tile(&self, coords: (u8, u8)) -> Option<Entity> {
+        self.tiles.get(&coords).copied()
+    }
+
+    pub fn occupant(&self, coords: (u8, u8)) -> Option<Entity> {
+        self.occupants.get(&coords).copied()
+    }
+
+    pub fn terrain(&self, coords: (u8, u8)) -> TerrainType {
+        self.terrain.get(&coords).copied().unwrap_or_default()
+    }
+
+    /// Terrain of every tile covered by a footprint placed on `corner`
+    pub fn terrain_under(&self, corner: (u8, u8), footprint: (u8, u8)) -> Vec<TerrainType> {
+        self.covered(corner, footprint)
+            .unwrap_or_else(|| vec![corner])
+            .into_iter()
+            .map(|coords| self.terrain(coords))
+            .collect()
+    }
+
+    pub fn occupants(&self) -> impl Iterator<Item = ((u8, u8), Entity)> + '_ {
+        self.occupants.iter().map(|(coords, entity)| (*coords, *entity))
+    }
+
+    /// Every coordinate covered by a footprint placed on `corner`, or None if part of it is off the grid
+    pub fn covered(&self, (i, j): (u8, u8), (width, depth): (u8, u8)) -> Option<Vec<(u8, u8)>> {
+        let covered = (i..i + width)
+            .flat_map(|x| (j..j + depth).map(move |z| (x, z)))
+            .collect::<Vec<(u8, u8)>>();
+
+        covered
+            .iter()
+            .all(|coords| self.tiles.contains_key(coords))
+            .then_some(covered)
+    }
+
+    /// Buildings sharing an edge with a footprint placed on `corner` - corners don't count
+    pub fn neighbours(&self, corner: (u8, u8), footprint: (u8, u8), ignore: Option<Entity>) -> Vec<Entity> {
+        let covered = self.covered(corner, footprint).unwrap_or_else(|| vec![corner]);
+        let mut neighbours = vec![];
+
+        for coords in covered.iter().flat_map(|coords| edge_neighbours(*coords)) {
+            let Some(occupant) = self.occupant(coords) else {
+                continue;
+            };
+
+            if !covered.contains(&coords) && Some(occupant) != ignore && !neighbours.contains(&occupant) {
+                neighbours.push(occupant);
+            }
+        }
+
+        neighbours
+    }
+}
+
+// Marker - land the player hasn't bought yet
+#[derive(Component)]
+pub struct LockedTile;
+
+/// Filter for tiles that can't be built on
+pub type BlockedTile = Or<(With<LockedTile>, With<Road>)>;
+
+#[derive(Resource)]
+pub struct TileMaterials {
+    // Owned and for sale variants of every terrain type
+    pub terrain: HashMap<TerrainType, (Handle<StandardMaterial>, Handle<StandardMaterial>)>,
+    pub hover: Handle<StandardMaterial>,
+    pub locked_hover: Handle<StandardMaterial>,
+    pub road: Handle<StandardMaterial>,
+}
+
+impl TileMaterials {
+    pub fn get(&self, terrain_type: TerrainType, locked: bool) -> Handle<StandardMaterial> {
+        let (owned, for_sale) = &self.terrain[&terrain_type];
+
+        if locked {
+            for_sale.clone()
+        } else {
+            owned.clone()
+        }
+    }
+
+    pub fn highlight(&self, terrain_type: TerrainType, locked: bool) -> Highlight<StandardMaterial> {
+        let default = self.get(terrain_type, locked);
+        let hover = if locked {
+            self.locked_hover.clone()
+        } else {
+            self.hover.clone()
+        };
+
+        Highlight {
+            hovered: Some(HighlightKind::Fixed(hover.clone())),
+            pressed: Some(HighlightKind::Fixed(hover)),
+            selected: Some(HighlightKind::Fixed(default)),
+        }
+    }
+
+    pub fn road_highlight(&self) -> Highlight<StandardMaterial> {
+        Highlight {
+            hovered: Some(HighlightKind::Fixed(self.hover.clone())),
+            pressed: Some(HighlightKind::Fixed(self.hover.clone())),
+            selected: Some(HighlightKind::Fixed(self.road.clone())),
+        }
+    }
+}
+
+/// Translucent materials for the building preview while placing
+#[derive(Resource)]
+pub struct GhostMaterials {
+    pub free: Handle<StandardMaterial>,
+    pub occupied: Handle<StandardMaterial>,
+}
+
+/// Sent when a tile is clicked
+pub struct TileClickEvent {
+    pub tile: Entity,
+}
+
+impl From<ListenedEvent<Click>> for TileClickEvent {
+    fn from(event: ListenedEvent<Click>) -> Self {
+        Self { tile: event.target }
     }
 }
 
-#[derive(Reflect, FromReflect, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[derive(Reflect, FromReflect, Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum BuildingType {
     CityCentre,
     Market,
@@ -54,6 +223,7 @@ pub enum BuildingType {
     Tree,
     Factory,
     Cabin,
+    Flowerbed,
 }
 
 impl BuildingType {
@@ -68,10 +238,16 @@ impl BuildingType {
             Tree => "Big Tree",
             Factory => "Factory",
             Cabin => "Cabin",
+            Flowerbed => "Flowerbed",
         }
         .to_string()
     }
 
+    /// Decorations produce nothing and can't be upgraded - they only make their surroundings nicer
+    pub fn is_decoration(&self) -> bool {
+        matches!(self, BuildingType::Flowerbed)
+    }
+
     pub fn get_transform(&self) -> Transform {
         use BuildingType::*;
 
@@ -86,10 +262,42 @@ impl BuildingType {
             CandyShop => Transform::from_scale(Vec3::new(1.2, 1.0, 1.2)),
             CoffeeShop => Transform::from_scale(Vec3::new(1.25, 1.0, 1.0)),
             Tree => Transform::from_scale(Vec3::new(3.0, 3.0, 3.0)),
-            Factory => Transform::from_scale(Vec3::new(0.23, 0.33, 0.23)).with_rotation(Quat::from_rotation_y(PI)),
+            Factory => Transform::from_scale(Vec3::new(0.46, 0.5, 0.46)).with_rotation(Quat::from_rotation_y(PI)),
             Cabin => Transform::from_xyz(1.0, 0.0, -1.0).with_scale(Vec3::new(0.004, 0.006, 0.005)),
+            Flowerbed => Transform::from_xyz(-1.0, 0.0, 0.2).with_scale(Vec3::splat(0.8)),
         }
     }
+
+    /// Tiles covered along x and z, starting from the tile the building is parented to
+    pub fn get_footprint(&self) -> (u8, u8) {
+        use BuildingType::*;
+
+        match self {
+            Factory => (2, 2),
+            _ => (1, 1),
+        }
+    }
+
+    /// Quarter turns swap the sides of the footprint
+    pub fn get_rotated_footprint(&self, rotation: u8) -> (u8, u8) {
+        let (width, depth) = self.get_footprint();
+
+        if rotation % 2 == 1 {
+            (depth, width)
+        } else {
+            (width, depth)
+        }
+    }
+
+    /// The base transform turned by a number of quarter turns and centred on the whole footprint
+    pub fn get_rotated_transform(&self, rotation: u8) -> Transform {
+        let (width, depth) = self.get_rotated_footprint(rotation);
+        let centre = Vec3::new(width as f32 - 1.0, 0.0, depth as f32 - 1.0) * TILE_SIZE / 2.0;
+
+        Transform::from_translation(centre)
+            * Transform::from_rotation(Quat::from_rotation_y(rotation as f32 * PI / 2.0))
+            * self.get_transform()
+    }
 }
 
 #[derive(Component, Reflect, FromReflect, Debug, PartialEq)]
@@ -97,23 +305,51 @@ pub struct Building {
     pub building_type: BuildingType,
     pub level: u8,
     pub yields: Vec<(ItemType, u32)>,
-    pub speed: u8,
+    // Seconds per production cycle
+    pub speed: f32,
+    // Quarter turns on top of the type's own transform, 0..4
+    pub rotation: u8,
 }
 
+#[allow(clippy::complexity)]
 pub fn spawn_grid(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     asset_server: Res<AssetServer>,
     mut next_setup_state: ResMut<NextState<SetupState>>,
+    land: Res<Land>,
+    mut grid_index: ResMut<GridIndex>,
+    terrain: Res<Terrain>,
+    roads: Res<Roads>,
+    loaded_save: Res<LoadedSave>,
 ) {
     let texture = asset_server.load("tile_texture.png");
 
-    let default_highlight = materials.add(StandardMaterial {
-        base_color_texture: Some(texture),
-        // unlit: true,
-        ..default()
-    });
+    let mut terrain_materials = HashMap::new();
+
+    for terrain_type in [
+        TerrainType::Grass,
+        TerrainType::Forest,
+        TerrainType::Fertile,
+        TerrainType::Rock,
+        TerrainType::Water,
+    ] {
+        let colour = terrain_type.get_colour();
+        let owned = materials.add(StandardMaterial {
+            base_color: colour,
+            base_color_texture: Some(texture.clone()),
+            ..default()
+        });
+        // Land that's for sale is darker
+        let for_sale = materials.add(StandardMaterial {
+            base_color: colour * Vec3::new(0.35, 0.35, 0.4),
+            base_color_texture: Some(texture.clone()),
+            ..default()
+        });
+
+        terrain_materials.insert(terrain_type, (owned, for_sale));
+    }
 
     let hover_highlight = materials.add(StandardMaterial {
         // base_color: Color::rgba(0.78, 0.0, 0.43, 0.5),
@@ -123,20 +359,72 @@ pub fn spawn_grid(
         ..default()
     });
 
+    let locked_hover_highlight = materials.add(StandardMaterial {
+        base_color: Color::rgba(0.95, 0.75, 0.3, 0.5),
+        ..default()
+    });
+
+    let road = materials.add(StandardMaterial {
+        base_color: Color::rgb(0.32, 0.32, 0.35),
+        perceptual_roughness: 0.9,
+        ..default()
+    });
+
+    let tile_materials = TileMaterials {
+        terrain: terrain_materials,
+        hover: hover_highlight,
+        locked_hover: locked_hover_highlight,
+        road,
+    };
+
+    let ghost_material = |base_color| StandardMaterial {
+        base_color,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    };
+
+    commands.insert_resource(GhostMaterials {
+        free: materials.add(ghost_material(Color::rgba(0.0, 1.0, 0.0, 0.35))),
+        occupied: materials.add(ghost_material(Color::rgba(1.0, 0.0, 0.0, 0.35))),
+    });
+
     let tile_plane = meshes.add(shape::Plane::from_size(TILE_SIZE).into());
 
-    for i in 0..=10u8 {
-        for j in 0..=10u8 {
-            spawn_tile(
+    // The starting land and anything already built on must stay usable, whatever the seed
+    let keep_dry = Land::default()
+        .owned
+        .into_iter()
+        .chain([CITY_CENTRE_COORDS, MARKET_COORDS, CONSTRUCT_COORDS])
+        .chain(
+            loaded_save
+                .0
+                .iter()
+                .flat_map(|save_file| save_file.buildings.iter())
+                .flat_map(SavedBuilding::covered),
+        )
+        .chain(roads.tiles.iter().copied())
+        .collect::<Vec<(u8, u8)>>();
+
+    grid_index.terrain = terrain.generate(GRID_SIZE, &keep_dry);
+
+    for i in 0..GRID_SIZE {
+        for j in 0..GRID_SIZE {
+            let tile = spawn_tile(
                 &mut commands,
                 tile_plane.clone(),
-                default_highlight.clone(),
-                hover_highlight.clone(),
+                &tile_materials,
                 i,
                 j,
+                grid_index.terrain((i, j)),
+                !land.owns((i, j)),
             );
+
+            grid_index.tiles.insert((i, j), tile);
         }
     }
+
+    commands.insert_resource(tile_materials);
     debug!("Finished spawning tiles");
     next_setup_state.set(SetupState::SpawnTileDone);
 }
@@ -146,268 +434,560 @@ pub struct UpgradeTarget {
     pub target_entity: Entity,
 }
 
+/// The see-through building shown on the hovered tile while placing
 #[derive(Component)]
-struct ConstructPreviewSphere;
+pub struct ConstructPreviewGhost {
+    occupied: bool,
+    // What the building would get if it was placed here
+    pub synergies: Synergies,
+}
 
 pub fn spawn_tile(
     commands: &mut Commands,
     mesh: Handle<Mesh>,
-    default_material: Handle<StandardMaterial>,
-    hover_material: Handle<StandardMaterial>,
+    tile_materials: &TileMaterials,
     x_offset: u8,
     z_offset: u8,
-) {
+    terrain_type: TerrainType,
+    locked: bool,
+) -> Entity {
     let x_pos = TILE_SIZE * x_offset as f32;
     let z_pos = TILE_SIZE * z_offset as f32;
 
-    commands
-        .spawn((
-            PbrBundle {
-                mesh,
-                material: default_material.clone(),
-                transform: Transform::from_xyz(x_pos, 0.0, z_pos),
-                ..default()
+    let mut tile = commands.spawn((
+        PbrBundle {
+            mesh,
+            material: tile_materials.get(terrain_type, locked),
+            transform: Transform::from_xyz(x_pos, 0.0, z_pos),
+            ..default()
+        },
+        PickableBundle::default(),
+        RaycastPickTarget::default(),
+        OnPointer::<Over>::run_callback(
+            |In(event): In<ListenedEvent<Over>>,
+             construct_state: Res<State<ConstructPhase>>,
+             mut callback_commands: Commands,
+             models: Res<Models>,
+             building_stash: Res<BuildingStash>,
+             buildings: Query<&Building>,
+             tiles: Query<&Tile>,
+             grid_index: Res<GridIndex>,
+             blocked_tiles: Query<(), BlockedTile>,
+             locked_tiles: Query<(), With<LockedTile>>,
+             adjacency_data: Res<AdjacencyData>,
+             terrain_data: Res<TerrainData>| {
+                // Nothing can be built on land that hasn't been bought
+                if construct_state.0 != ConstructPhase::Preview || locked_tiles.contains(event.target) {
+                    return Bubble::Up;
+                }
+
+                // A building being moved previews as itself
+                let building_type = building_stash.building_type.or_else(|| {
+                    building_stash
+                        .relocating
+                        .and_then(|entity| buildings.get(entity).ok())
+                        .map(|building| building.building_type)
+                });
+
+                let (Some(building_type), Ok(tile)) = (building_type, tiles.get(event.target)) else {
+                    return Bubble::Up;
+                };
+
+                let footprint = building_type.get_rotated_footprint(building_stash.rotation);
+                let occupied = !footprint_is_free(
+                    &grid_index,
+                    &blocked_tiles,
+                    building_type,
+                    tile.coords(),
+                    footprint,
+                    building_stash.relocating,
+                );
+
+                let neighbours = grid_index
+                    .neighbours(tile.coords(), footprint, building_stash.relocating)
+                    .into_iter()
+                    .filter_map(|neighbour| buildings.get(neighbour).ok().map(|building| building.building_type))
+                    .collect::<Vec<BuildingType>>();
+
+                let attractiveness = adjacency_data.attractiveness_at(
+                    building_type,
+                    grid_index.occupants().filter_map(|(coords, occupant)| {
+                        let decoration = buildings.get(occupant).ok()?.building_type;
+
+                        (decoration.is_decoration() && Some(occupant) != building_stash.relocating)
+                            .then_some((coords, decoration))
+                    }),
+                    tile.coords(),
+                    footprint,
+                );
+
+                let mut synergies = adjacency_data.synergies(building_type, &neighbours, attractiveness);
+                terrain_data.apply(
+                    &mut synergies,
+                    building_type,
+                    &grid_index.terrain_under(tile.coords(), footprint),
+                );
+
+                let ghost = callback_commands
+                    .spawn(SceneBundle {
+                        scene: models.get_scene(building_type),
+                        transform: building_type.get_rotated_transform(building_stash.rotation),
+                        ..default()
+                    })
+                    .insert(ConstructPreviewGhost { occupied, synergies })
+                    .insert(Name::new("Preview ghost"))
+                    .id();
+
+                callback_commands.entity(event.target).add_child(ghost);
+                Bubble::Up
             },
-            PickableBundle::default(),
-            RaycastPickTarget::default(),
-            // TODO: Transparent version of the building rather than a sphere
-            OnPointer::<Over>::run_callback(
-                |In(event): In<ListenedEvent<Over>>,
-                 construct_state: Res<State<ConstructPhase>>,
-                 mut callback_commands: Commands,
-                 mut meshes: ResMut<Assets<Mesh>>,
-                 mut materials: ResMut<Assets<StandardMaterial>>| {
-                    if construct_state.0 == ConstructPhase::Preview {
-                        // Spawn a transparent sphere indicator
-                        let sphere = callback_commands
-                            .spawn(PbrBundle {
-                                mesh: meshes.add(
-                                    Mesh::try_from(shape::Icosphere {
-                                        radius: 2.5,
-                                        subdivisions: 3,
-                                    })
-                                    .unwrap(),
-                                ),
-                                material: materials.add(StandardMaterial {
-                                    base_color: Color::rgba(0.0, 1.0, 0.0, 0.25),
-                                    alpha_mode: AlphaMode::Blend,
-                                    unlit: true,
-                                    ..default()
-                                }),
-                                transform: Transform::from_xyz(0.0, 2.5, 0.0),
-                                ..default()
-                            })
-                            .insert(ConstructPreviewSphere)
-                            .id();
-
-                        callback_commands.entity(event.target).add_child(sphere);
-                    }
-                    Bubble::Up
-                },
-            ),
-            // Find and delete transparent preview sphere
-            OnPointer::<Out>::run_callback(
-                |In(event): In<ListenedEvent<Out>>,
-                 construct_state: Res<State<ConstructPhase>>,
-                 mut callback_commands: Commands,
-                 preview_spheres: Query<(Entity, &Parent), With<ConstructPreviewSphere>>| {
-                    if construct_state.0 == ConstructPhase::Preview {
-                        for (sphere_entity, parent) in preview_spheres.iter() {
-                            debug!("Sphere: {:?}", sphere_entity);
-                            if parent.get() == event.target {
-                                callback_commands.entity(sphere_entity).despawn_recursive();
-                                break;
-                            }
-                        }
-                    }
-                    Bubble::Up
-                },
-            ),
-            OnPointer::<Click>::run_callback(
-                // This is a big closure lol
-                |In(event): In<ListenedEvent<Click>>,
-                 buildings: Query<(Entity, &Parent, &Building), With<Building>>,
-                 mut next_ui_state: ResMut<NextState<UiState>>,
-                 camera_state: Res<State<CameraState>>,
-                 mut previous_camera_state: ResMut<PreviousCameraState>,
-                 mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
-                 construct_state: Res<State<ConstructPhase>>,
-                 mut next_construct_state: ResMut<NextState<ConstructPhase>>,
-                 mut callback_commands: Commands,
-                 preview_spheres: Query<(&Parent, Entity), With<ConstructPreviewSphere>>,
-                 mut building_stash: ResMut<BuildingStash>,
-                 mut send_upgrade_target: EventWriter<UpgradeTarget>,
-                 upgrade_data: Res<UpgradeData>,
-                 mut timers: ResMut<Timers>,
-                 // mut upgrade_target: ResMut<UpgradeTarget>,
-                 models: Res<Models>| {
-                    if construct_state.0 == ConstructPhase::Normal {
-                        for (entity, parent, building) in buildings.iter() {
-                            if parent.get() == event.target {
-                                // We have found the targetted building
-                                previous_camera_state.0 = Some(camera_state.0.clone());
-                                send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::Frozen));
-
-                                match building.building_type {
-                                    // BuildingType::CityCentre => {
-                                    //     //
-                                    //     next_ui_state.set(UiState::CityCentreInfo)
-                                    // }
-                                    BuildingType::Market => {
-                                        //
-                                        next_ui_state.set(UiState::Market)
-                                    }
-                                    BuildingType::Construct => {
-                                        //
-                                        next_ui_state.set(UiState::Construct)
-                                    }
-                                    _ => {
-                                        next_ui_state.set(UiState::Upgrade);
-                                        send_upgrade_target.send(UpgradeTarget { target_entity: entity });
-                                        // upgrade_target.target_entity = entity;
-                                    }
-                                }
-
-                                break;
-                            }
-                        }
-                    } else if construct_state.0 == ConstructPhase::Preview {
-                        // Check if the sphere is on a tile without a building
-                        let mut can_spawn_here = true;
-                        if let Some((sphere_parent, sphere_entity)) = preview_spheres.iter().next() {
-                            for (_, building_parent, _) in buildings.iter() {
-                                if sphere_parent.get() == event.target && building_parent.get() == event.target {
-                                    can_spawn_here = false;
-                                }
-                            }
-
-                            if can_spawn_here {
-                                if let Some(building_type) = building_stash.0 {
-                                    callback_commands.entity(sphere_entity).despawn_recursive();
-                                    // TODO refactor this into a generic spawn building fn
-
-                                    let yield_data = &upgrade_data.map[&building_type][&1].yields;
-                                    let empty_yields = yield_data.map(|(item_type, _)| (item_type, 0u32));
-
-                                    let building = callback_commands
-                                        .spawn(SceneBundle {
-                                            scene: match building_type {
-                                                BuildingType::CandyShop => models.candy_shop_scene.clone(),
-                                                BuildingType::CoffeeShop => models.coffee_shop_scene.clone(),
-                                                BuildingType::Tree => models.tree_scene.clone(),
-                                                BuildingType::Factory => models.factory_scene.clone(),
-                                                BuildingType::Cabin => models.cabin_scene.clone(),
-                                                // This wildcard case will never happen
-                                                _ => models.city_centre_scene.clone(),
-                                            },
-                                            transform: building_type.get_transform(),
-                                            ..default()
-                                        })
-                                        .insert(Building {
-                                            building_type,
-                                            level: 1,
-                                            yields: empty_yields.to_vec(),
-                                            speed: upgrade_data.map[&building_type][&1].speed,
-                                        })
-                                        .id();
-
-                                    timers.add_timer(building, upgrade_data.map[&building_type][&1].speed);
-
-                                    callback_commands.entity(event.target).add_child(building);
-                                }
-
-                                building_stash.0 = None;
-                                next_construct_state.set(ConstructPhase::Normal);
-                                send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::CursorLocked));
-                            }
+        ),
+        // Find and delete the preview ghost
+        OnPointer::<Out>::run_callback(
+            |In(event): In<ListenedEvent<Out>>,
+             construct_state: Res<State<ConstructPhase>>,
+             mut callback_commands: Commands,
+             preview_ghosts: Query<(Entity, &Parent), With<ConstructPreviewGhost>>| {
+                if construct_state.0 == ConstructPhase::Preview {
+                    for (ghost_entity, parent) in preview_ghosts.iter() {
+                        if parent.get() == event.target {
+                            callback_commands.entity(ghost_entity).despawn_recursive();
                         }
                     }
+                }
+                Bubble::Up
+            },
+        ),
+        OnPointer::<Click>::send_event::<TileClickEvent>(),
+    ));
+
+    tile.insert(tile_materials.highlight(terrain_type, locked))
+        .insert(Tile::new(x_offset, z_offset))
+        .insert(Name::new(format!("Tile ({x_offset},{z_offset})")));
+
+    if locked {
+        tile.insert(LockedTile);
+    }
+
+    tile.id()
+}
+
+/// Opens the UI for whatever is on the clicked tile
+#[allow(clippy::complexity)]
+fn open_clicked_tile(
+    mut tile_click_events: EventReader<TileClickEvent>,
+    construct_state: Res<State<ConstructPhase>>,
+    buildings: Query<&Building>,
+    tiles: Query<&Tile>,
+    grid_index: Res<GridIndex>,
+    locked_tiles: Query<(), With<LockedTile>>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+    camera_state: Res<State<CameraState>>,
+    mut previous_camera_state: ResMut<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut send_upgrade_target: EventWriter<UpgradeTarget>,
+    mut land_target: ResMut<LandTarget>,
+) {
+    for event in tile_click_events.iter() {
+        trace!("{:?}", event.tile);
+
+        if construct_state.0 != ConstructPhase::Normal {
+            continue;
+        }
+
+        if locked_tiles.contains(event.tile) {
+            previous_camera_state.0 = Some(camera_state.0.clone());
+            send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::Frozen));
 
-                    trace!("{:?}", event.target);
-                    Bubble::Up
+            land_target.0 = Some(event.tile);
+            next_ui_state.set(UiState::BuyLand);
+            continue;
+        }
+
+        // Any tile under a building opens it, not just the one it is parented to
+        let Some((entity, building)) = tiles
+            .get(event.tile)
+            .ok()
+            .and_then(|tile| grid_index.occupant(tile.coords()))
+            .and_then(|entity| buildings.get(entity).ok().map(|building| (entity, building)))
+        else {
+            continue;
+        };
+
+        previous_camera_state.0 = Some(camera_state.0.clone());
+        send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::Frozen));
+
+        match building.building_type {
+            BuildingType::CityCentre => {
+                //
+                next_ui_state.set(UiState::CityCentreInfo)
+            }
+            BuildingType::Market => {
+                //
+                next_ui_state.set(UiState::Market)
+            }
+            BuildingType::Construct => {
+                //
+                next_ui_state.set(UiState::Construct)
+            }
+            _ => {
+                next_ui_state.set(UiState::Upgrade);
+                send_upgrade_target.send(UpgradeTarget { target_entity: entity });
+            }
+        }
+    }
+}
+
+/// Places the building bought from the Construct on the clicked tile
+#[allow(clippy::complexity)]
+fn place_building(
+    mut tile_click_events: EventReader<TileClickEvent>,
+    construct_state: Res<State<ConstructPhase>>,
+    mut next_construct_state: ResMut<NextState<ConstructPhase>>,
+    mut commands: Commands,
+    mut buildings: Query<(Entity, &Parent, &mut Building)>,
+    tiles: Query<&Tile>,
+    grid_index: Res<GridIndex>,
+    blocked_tiles: Query<(), BlockedTile>,
+    preview_ghosts: Query<Entity, With<ConstructPreviewGhost>>,
+    mut building_stash: ResMut<BuildingStash>,
+    upgrade_data: Res<UpgradeData>,
+    mut timers: ResMut<Timers>,
+    models: Res<Models>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut send_game_event: EventWriter<GameEvent>,
+    mut statistics: ResMut<Statistics>,
+) {
+    for event in tile_click_events.iter() {
+        if construct_state.0 != ConstructPhase::Preview {
+            continue;
+        }
+
+        let building_type = building_stash.building_type.or_else(|| {
+            building_stash
+                .relocating
+                .and_then(|entity| buildings.get(entity).ok())
+                .map(|(_, _, building)| building.building_type)
+        });
+
+        let (Some(building_type), Ok(tile)) = (building_type, tiles.get(event.tile)) else {
+            continue;
+        };
+
+        // Every covered tile has to be owned and empty - a moving building may overlap where it was
+        if !footprint_is_free(
+            &grid_index,
+            &blocked_tiles,
+            building_type,
+            tile.coords(),
+            building_type.get_rotated_footprint(building_stash.rotation),
+            building_stash.relocating,
+        ) {
+            continue;
+        }
+
+        for ghost_entity in preview_ghosts.iter() {
+            commands.entity(ghost_entity).despawn_recursive();
+        }
+
+        if let Some(entity) = building_stash.relocating.take() {
+            // Level, yields and timer all live on the entity, so only the parent changes
+            if let Ok((_, parent, mut building)) = buildings.get_mut(entity) {
+                if let (Ok(from), Ok(to)) = (tiles.get(parent.get()), tiles.get(event.tile)) {
+                    statistics.move_building(building.building_type, from, to);
+                }
+
+                building.rotation = building_stash.rotation;
+                commands
+                    .entity(entity)
+                    .insert(building.building_type.get_rotated_transform(building.rotation));
+            }
+
+            commands.entity(entity).insert(Visibility::Inherited);
+            commands.entity(event.tile).add_child(entity);
+        } else {
+            let (empty_yields, speed) = match upgrade_data.level_stats(building_type, 1) {
+                Some(level_stats) => (
+                    level_stats.yields.map(|(item_type, _)| (item_type, 0u32)).to_vec(),
+                    level_stats.speed,
+                ),
+                None => (vec![], 0.0),
+            };
+
+            let entity = spawn_building(
+                &mut commands,
+                &models,
+                &mut timers,
+                event.tile,
+                Building {
+                    building_type,
+                    level: 1,
+                    yields: empty_yields,
+                    speed,
+                    rotation: building_stash.rotation,
                 },
-            ),
-        ))
-        .insert(Highlight {
-            hovered: Some(HighlightKind::Fixed(hover_material.clone())),
-            pressed: Some(HighlightKind::Fixed(hover_material)),
-            selected: Some(HighlightKind::Fixed(default_material)),
+            );
+
+            send_game_event.send(GameEvent::Constructed {
+                entity,
+                building_type,
+                cost: building_stash.price,
+            });
+        }
+
+        building_stash.building_type = None;
+        next_construct_state.set(ConstructPhase::Normal);
+        send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::CursorLocked));
+        break;
+    }
+}
+
+/// Whether a footprint placed on `corner` lies on owned land the building can stand on, without roads or
+/// other buildings in the way
+fn footprint_is_free(
+    grid_index: &GridIndex,
+    blocked_tiles: &Query<(), BlockedTile>,
+    building_type: BuildingType,
+    corner: (u8, u8),
+    footprint: (u8, u8),
+    ignore: Option<Entity>,
+) -> bool {
+    let Some(covered) = grid_index.covered(corner, footprint) else {
+        return false;
+    };
+
+    covered.iter().all(|coords| {
+        grid_index
+            .tile(*coords)
+            .is_some_and(|tile| !blocked_tiles.contains(tile))
+            && grid_index.terrain(*coords).allows(building_type)
+            && grid_index
+                .occupant(*coords)
+                .filter(|occupant| Some(*occupant) != ignore)
+                .is_none()
+    })
+}
+
+/// Keeps the occupants in the GridIndex in line with the buildings' footprints
+#[allow(clippy::complexity)]
+fn update_occupancy(
+    changed_buildings: Query<(), (With<Building>, Or<(Changed<Parent>, Changed<Transform>)>)>,
+    mut removed_buildings: RemovedComponents<Building>,
+    buildings: Query<(Entity, &Parent, &Building)>,
+    tiles: Query<&Tile>,
+    mut grid_index: ResMut<GridIndex>,
+) {
+    if changed_buildings.is_empty() && removed_buildings.iter().count() == 0 {
+        return;
+    }
+
+    let mut occupancy = HashMap::new();
+
+    for (entity, parent, building) in buildings.iter() {
+        let Ok(tile) = tiles.get(parent.get()) else {
+            continue;
+        };
+
+        let footprint = building.building_type.get_rotated_footprint(building.rotation);
+        let covered = grid_index
+            .covered(tile.coords(), footprint)
+            .unwrap_or_else(|| vec![tile.coords()]);
+
+        for coords in covered {
+            occupancy.insert(coords, entity);
+        }
+    }
+
+    // Only touch the resource when something moved, so change detection stays meaningful
+    if grid_index.occupants != occupancy {
+        grid_index.occupants = occupancy;
+    }
+}
+
+/// Turns the building waiting to be placed, along with its ghost
+fn rotate_preview(
+    keybinds: Res<Keybinds>,
+    keys: Res<Input<KeyCode>>,
+    mut building_stash: ResMut<BuildingStash>,
+    buildings: Query<&Building>,
+    mut preview_ghosts: Query<&mut Transform, With<ConstructPreviewGhost>>,
+) {
+    if keys.just_pressed(keybinds.rotate_building_left) {
+        building_stash.rotation = (building_stash.rotation + 3) % 4;
+    } else if keys.just_pressed(keybinds.rotate_building_right) {
+        building_stash.rotation = (building_stash.rotation + 1) % 4;
+    } else {
+        return;
+    }
+
+    let building_type = building_stash.building_type.or_else(|| {
+        building_stash
+            .relocating
+            .and_then(|entity| buildings.get(entity).ok())
+            .map(|building| building.building_type)
+    });
+
+    let Some(building_type) = building_type else {
+        return;
+    };
+
+    for mut transform in preview_ghosts.iter_mut() {
+        *transform = building_type.get_rotated_transform(building_stash.rotation);
+    }
+}
+
+/// Swaps the materials of the ghost's scene for the translucent ones once it has been spawned
+fn tint_preview_ghost(
+    preview_ghosts: Query<(Entity, &ConstructPreviewGhost)>,
+    children: Query<&Children>,
+    mut mesh_materials: Query<&mut Handle<StandardMaterial>>,
+    ghost_materials: Res<GhostMaterials>,
+) {
+    for (ghost_entity, ghost) in preview_ghosts.iter() {
+        let material = if ghost.occupied {
+            &ghost_materials.occupied
+        } else {
+            &ghost_materials.free
+        };
+
+        for descendant in children.iter_descendants(ghost_entity) {
+            if let Ok(mut mesh_material) = mesh_materials.get_mut(descendant) {
+                if *mesh_material != *material {
+                    *mesh_material = material.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Spawns the scene for a building as a child of a tile and starts its production timer
+pub fn spawn_building(
+    commands: &mut Commands,
+    models: &Models,
+    timers: &mut Timers,
+    tile_entity: Entity,
+    building: Building,
+) -> Entity {
+    let speed = building.speed;
+    let is_decoration = building.building_type.is_decoration();
+
+    let entity = commands
+        .spawn(SceneBundle {
+            scene: models.get_scene(building.building_type),
+            transform: building.building_type.get_rotated_transform(building.rotation),
+            ..default()
         })
-        .insert(Tile::new(x_pos, z_pos))
-        .insert(Name::new(format!("Tile ({x_pos},{z_pos})")));
+        .insert(building)
+        .id();
+
+    if !is_decoration {
+        timers.add_timer(entity, speed);
+    }
+
+    commands.entity(tile_entity).add_child(entity);
+
+    entity
 }
 
+#[allow(clippy::complexity)]
 fn setup_buildings(
     mut commands: Commands,
-    tiles: Query<(Entity, &Tile)>,
+    grid_index: Res<GridIndex>,
     models: Res<Models>,
     mut next_setup_state: ResMut<NextState<SetupState>>,
     upgrade_data: Res<UpgradeData>,
     mut timers: ResMut<Timers>,
+    loaded_save: Res<LoadedSave>,
+    construct_data: Res<ConstructData>,
 ) {
-    for (tile_entity, tile) in tiles.iter() {
-        if tile.x == 70.0 && tile.z == 60.0 {
-            let building = commands
-                .spawn(SceneBundle {
-                    scene: models.city_centre_scene.clone(),
-                    transform: BuildingType::CityCentre.get_transform(),
-                    ..default()
-                })
-                .insert(Building {
-                    building_type: BuildingType::CityCentre,
-                    level: 1,
-                    yields: vec![
-                        (ItemType::BronzeCoin, 0),
-                        (ItemType::SilverCoin, 0),
-                        (ItemType::GoldCoin, 0),
-                    ],
-                    speed: upgrade_data.map[&BuildingType::CityCentre][&1].speed,
-                })
-                .id();
-
-            timers.add_timer(building, upgrade_data.map[&BuildingType::CityCentre][&1].speed);
-
-            commands.entity(tile_entity).add_child(building);
-        } else if tile.x == 70.0 && tile.z == 70.0 {
-            let building = commands
-                .spawn(SceneBundle {
-                    scene: models.market_scene.clone(),
-                    transform: BuildingType::Market.get_transform(),
-                    ..default()
-                })
-                .insert(Building {
-                    building_type: BuildingType::Market,
-                    level: 1,
-                    yields: vec![],
-                    speed: 30,
-                })
-                .id();
-
-            // Market refreshes every 30s
-            timers.add_timer(building, 30);
-
-            commands.entity(tile_entity).add_child(building);
-        } else if tile.x == 80.0 && tile.z == 40.0 {
-            let building = commands
-                .spawn(SceneBundle {
-                    scene: models.construction_scene.clone(),
-                    transform: BuildingType::Construct.get_transform(),
-                    ..default()
-                })
-                .insert(Building {
-                    building_type: BuildingType::Construct,
-                    level: 1,
-                    yields: vec![],
-                    speed: 0,
-                })
-                .id();
+    // Without a save file, only the default buildings are spawned
+    let saved_buildings = loaded_save.0.as_ref().map(|save_file| &save_file.buildings);
+
+    if let (Some(tile_entity), None) = (grid_index.tile(CITY_CENTRE_COORDS), saved_buildings) {
+        spawn_building(
+            &mut commands,
+            &models,
+            &mut timers,
+            tile_entity,
+            Building {
+                building_type: BuildingType::CityCentre,
+                level: 1,
+                yields: vec![
+                    (ItemType::BronzeCoin, 0),
+                    (ItemType::SilverCoin, 0),
+                    (ItemType::GoldCoin, 0),
+                ],
+                speed: upgrade_data.map[&BuildingType::CityCentre][&1].speed,
+                rotation: 0,
+            },
+        );
+    }
 
-            // Boosted items refresh every 30s
-            // This timer has no semantic meaning - I'm only using the Construct as a target entity
-            // because CityCentre is already occupied by another timer
-            timers.add_timer(building, 30);
+    if let Some(tile_entity) = grid_index.tile(MARKET_COORDS) {
+        let building = commands
+            .spawn(SceneBundle {
+                scene: models.market_scene.clone(),
+                transform: BuildingType::Market.get_transform(),
+                ..default()
+            })
+            .insert(Building {
+                building_type: BuildingType::Market,
+                level: 1,
+                yields: vec![],
+                speed: 0.0,
+                rotation: 0,
+            })
+            .id();
+
+        // Restocks are run by the Scheduler, so the Market has no timer of its own
+        commands.entity(tile_entity).add_child(building);
+    }
+
+    if let Some(tile_entity) = grid_index.tile(CONSTRUCT_COORDS) {
+        let building = commands
+            .spawn(SceneBundle {
+                scene: models.construction_scene.clone(),
+                transform: BuildingType::Construct.get_transform(),
+                ..default()
+            })
+            .insert(Building {
+                building_type: BuildingType::Construct,
+                level: 1,
+                yields: vec![],
+                speed: 0.0,
+                rotation: 0,
+            })
+            .id();
+
+        commands.entity(tile_entity).add_child(building);
+    }
+
+    for saved_building in saved_buildings.into_iter().flatten() {
+        let Some(tile_entity) = grid_index.tile(saved_building.coords()) else {
+            continue;
+        };
+
+        let entity = spawn_building(
+            &mut commands,
+            &models,
+            &mut timers,
+            tile_entity,
+            Building {
+                building_type: saved_building.building_type,
+                level: saved_building.level,
+                yields: saved_building.yields.clone(),
+                speed: upgrade_data
+                    .level_stats(saved_building.building_type, saved_building.level)
+                    .map_or(0.0, |level_stats| level_stats.speed),
+                rotation: saved_building.rotation,
+            },
+        );
 
-            commands.entity(tile_entity).add_child(building);
+        if let Some(elapsed) = saved_building.construction {
+            commands.entity(entity).insert(UnderConstruction::new(
+                construct_data.build_time(saved_building.building_type),
+                elapsed,
+            ));
         }
     }
 
@@ -1,14 +1,32 @@
 use bevy::prelude::*;
 
+mod adjacency;
 mod camera;
+mod construction;
 mod data;
+mod events;
+mod game_clock;
 mod grid;
+mod modifiers;
+mod roads;
+mod save;
+mod scheduler;
+mod terrain;
 mod timers;
 mod ui;
 
+pub use adjacency::*;
 pub use camera::*;
+pub use construction::*;
 pub use data::*;
+pub use events::*;
+pub use game_clock::*;
 pub use grid::*;
+pub use modifiers::*;
+pub use roads::*;
+pub use save::*;
+pub use scheduler::*;
+pub use terrain::*;
 pub use timers::*;
 pub use ui::*;
 
@@ -24,6 +42,13 @@ pub struct Keybinds {
     pub move_down: KeyCode,
     pub toggle_mouse_lock: KeyCode,
     pub toggle_inventory: KeyCode,
+    pub toggle_achievements: KeyCode,
+    pub toggle_statistics: KeyCode,
+    pub rotate_building_left: KeyCode,
+    pub rotate_building_right: KeyCode,
+    pub toggle_roads: KeyCode,
+    pub toggle_pause: KeyCode,
+    pub change_game_speed: KeyCode,
     pub exit_uistate: KeyCode,
 }
 
@@ -38,6 +63,13 @@ impl Default for Keybinds {
             move_down: KeyCode::LShift,
             toggle_mouse_lock: KeyCode::F,
             toggle_inventory: KeyCode::E,
+            toggle_achievements: KeyCode::Q,
+            toggle_statistics: KeyCode::T,
+            rotate_building_left: KeyCode::Z,
+            rotate_building_right: KeyCode::X,
+            toggle_roads: KeyCode::R,
+            toggle_pause: KeyCode::P,
+            change_game_speed: KeyCode::G,
             exit_uistate: KeyCode::Escape,
         }
     }
@@ -53,6 +85,24 @@ pub struct Models {
     pub tree_scene: Handle<Scene>,
     pub factory_scene: Handle<Scene>,
     pub cabin_scene: Handle<Scene>,
+    pub flowerbed_scene: Handle<Scene>,
+}
+
+impl Models {
+    pub fn get_scene(&self, building_type: BuildingType) -> Handle<Scene> {
+        use BuildingType::*;
+        match building_type {
+            CityCentre => self.city_centre_scene.clone(),
+            Market => self.market_scene.clone(),
+            Construct => self.construction_scene.clone(),
+            CandyShop => self.candy_shop_scene.clone(),
+            CoffeeShop => self.coffee_shop_scene.clone(),
+            Tree => self.tree_scene.clone(),
+            Factory => self.factory_scene.clone(),
+            Cabin => self.cabin_scene.clone(),
+            Flowerbed => self.flowerbed_scene.clone(),
+        }
+    }
 }
 
 pub fn load_models(mut commands: Commands, asset_server: Res<AssetServer>) {
@@ -65,6 +115,7 @@ pub fn load_models(mut commands: Commands, asset_server: Res<AssetServer>) {
         tree_scene: asset_server.load("tree.glb#Scene0"),
         factory_scene: asset_server.load("factory.glb#Scene0"),
         cabin_scene: asset_server.load("cabin.glb#Scene0"),
+        flowerbed_scene: asset_server.load("flowerbed.glb#Scene0"),
     });
 }
 
@@ -77,38 +128,3 @@ pub fn set_cursor_lock(window: &mut Window, lock: bool) {
         window.cursor.visible = true;
     }
 }
-
-pub struct AutoSavePlugin;
-
-impl Plugin for AutoSavePlugin {
-    fn build(&self, app: &mut App) {
-        app.init_resource::<AutoSaver>().add_system(auto_save);
-    }
-}
-
-// TODO: Hook this up to a serializer and save to file
-#[derive(Resource)]
-pub struct AutoSaver {
-    save_timer: Timer,
-}
-
-impl Default for AutoSaver {
-    fn default() -> Self {
-        Self {
-            save_timer: Timer::from_seconds(5.0, TimerMode::Repeating),
-        }
-    }
-}
-
-pub fn auto_save(
-    time: Res<Time>,
-    mut autosaver: ResMut<AutoSaver>,
-    inventory: Res<Inventory>,
-    buildings: Query<&Building>,
-) {
-    autosaver.save_timer.tick(time.delta());
-
-    if autosaver.save_timer.just_finished() {
-        debug!("Should be autosaving now");
-    }
-}
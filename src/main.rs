@@ -39,13 +39,37 @@ fn main() {
         .add_plugin(GameCameraPlugin)
         .add_plugin(InventoryPlugin)
         .add_plugin(MarketPlugin)
+        .add_plugin(MinimapPlugin)
         .add_plugin(ConstructPlugin)
         .add_plugin(UpgradePlugin)
+        .add_plugin(GameClockPlugin)
+        .add_plugin(SchedulerPlugin)
         .add_plugin(TimerPlugin)
         .add_plugin(ResourceInspectorPlugin::<Timers>::default())
+        .add_plugin(ResourceInspectorPlugin::<Scheduler>::default())
+        .add_plugin(CityCentrePlugin)
+        .add_plugin(BankPlugin)
+        .add_plugin(LedgerPlugin)
+        .add_plugin(CityEventPlugin)
+        .add_plugin(LandPlugin)
+        .add_plugin(AdjacencyPlugin)
+        .add_plugin(RoadPlugin)
+        .add_plugin(ConstructionPlugin)
+        .add_plugin(AchievementPlugin)
+        .add_plugin(QuestPlugin)
+        .add_plugin(StatisticsPlugin)
         .add_plugin(AutoSavePlugin)
+        .add_event::<GameEvent>()
         .init_resource::<Keybinds>()
         .init_resource::<UpgradeData>()
+        .init_resource::<AchievementData>()
+        .init_resource::<QuestData>()
+        .init_resource::<BankData>()
+        .init_resource::<UpkeepData>()
+        .init_resource::<CityEventData>()
+        .init_resource::<AdjacencyData>()
+        .init_resource::<ConstructData>()
+        .init_resource::<TerrainData>()
         .insert_resource(Msaa::default())
         .insert_resource(SelectionSettings {
             click_nothing_deselect_all: true,
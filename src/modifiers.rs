@@ -0,0 +1,55 @@
+use crate::*;
+
+/// A temporary effect on production or prices. Percentages are of the normal value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Modifier {
+    Yield(BuildingType, u32),
+    SellPrice(ItemType, u32),
+    BuyPrice(ItemType, u32),
+    /// The market stocks none of this item
+    Shortage(ItemType),
+}
+
+/// Every modifier currently in effect - production and pricing read from here rather than from
+/// whatever caused the modifier. City events are the only writer, so `set` replaces the lot
+#[derive(Resource, Default)]
+pub struct Modifiers {
+    active: Vec<Modifier>,
+}
+
+impl Modifiers {
+    pub fn set(&mut self, modifiers: Vec<Modifier>) {
+        self.active = modifiers;
+    }
+
+    pub fn yield_percent(&self, building_type: BuildingType) -> u32 {
+        let share = self.active.iter().fold(1.0, |share, modifier| match modifier {
+            Modifier::Yield(target, percent) if *target == building_type => share * *percent as f32 / 100.0,
+            _ => share,
+        });
+
+        (share * 100.0).round() as u32
+    }
+
+    pub fn sell_price(&self, item_type: ItemType, price: u32) -> u32 {
+        let share = self.active.iter().fold(1.0, |share, modifier| match modifier {
+            Modifier::SellPrice(target, percent) if *target == item_type => share * *percent as f32 / 100.0,
+            _ => share,
+        });
+
+        (price as f32 * share).round() as u32
+    }
+
+    pub fn buy_price(&self, item_type: ItemType, price: u32) -> u32 {
+        let share = self.active.iter().fold(1.0, |share, modifier| match modifier {
+            Modifier::BuyPrice(target, percent) if *target == item_type => share * *percent as f32 / 100.0,
+            _ => share,
+        });
+
+        (price as f32 * share).round() as u32
+    }
+
+    pub fn is_short(&self, item_type: ItemType) -> bool {
+        self.active.contains(&Modifier::Shortage(item_type))
+    }
+}
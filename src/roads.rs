@@ -0,0 +1,252 @@
+use std::collections::VecDeque;
+
+use bevy::utils::HashSet;
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+pub const ROAD_PRICE: u32 = 10;
+/// Share of normal production for buildings without a road to the City Centre, in percent
+pub const DISCONNECTED_PRODUCTION: u32 = 50;
+
+/// Coordinates of every tile with a road on it
+#[derive(Resource, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Roads {
+    pub tiles: Vec<(u8, u8)>,
+}
+
+// Marker - tile with a road painted on it
+#[derive(Component)]
+pub struct Road;
+
+// Marker - building that can't reach the City Centre by road
+#[derive(Component)]
+pub struct Disconnected;
+
+pub struct RoadPlugin;
+
+impl Plugin for RoadPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Roads>()
+            .add_system(draw_road_hint.in_schedule(OnEnter(ConstructPhase::Roads)))
+            .add_system(undraw_road_hint.in_schedule(OnExit(ConstructPhase::Roads)))
+            .add_systems((toggle_road_mode, paint_road, update_road_tiles, update_connectivity).chain());
+    }
+}
+
+#[allow(clippy::complexity)]
+fn toggle_road_mode(
+    keybinds: Res<Keybinds>,
+    keys: Res<Input<KeyCode>>,
+    ui_state: Res<State<UiState>>,
+    construct_state: Res<State<ConstructPhase>>,
+    mut next_construct_state: ResMut<NextState<ConstructPhase>>,
+    camera_state: Res<State<CameraState>>,
+    mut previous_camera_state: ResMut<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+) {
+    if construct_state.0 == ConstructPhase::Roads {
+        if keys.just_pressed(keybinds.toggle_roads) || keys.just_pressed(keybinds.exit_uistate) {
+            next_construct_state.set(ConstructPhase::Normal);
+            send_change_camera_state_event.send(ChangeCameraStateEvent(
+                previous_camera_state.0.clone().unwrap_or(CameraState::CursorLocked),
+            ));
+        }
+    } else if construct_state.0 == ConstructPhase::Normal
+        && ui_state.0 == UiState::None
+        && keys.just_pressed(keybinds.toggle_roads)
+    {
+        previous_camera_state.0 = Some(camera_state.0.clone());
+        next_construct_state.set(ConstructPhase::Roads);
+        send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::ConstructPreview));
+    }
+}
+
+/// Lays a road on a clicked tile, or takes it up again if there already is one
+fn paint_road(
+    mut tile_click_events: EventReader<TileClickEvent>,
+    construct_state: Res<State<ConstructPhase>>,
+    tiles: Query<&Tile, Without<LockedTile>>,
+    grid_index: Res<GridIndex>,
+    mut roads: ResMut<Roads>,
+    mut inventory: ResMut<Inventory>,
+) {
+    for event in tile_click_events.iter() {
+        if construct_state.0 != ConstructPhase::Roads {
+            continue;
+        }
+
+        // Roads only go on owned, empty, solid land
+        let Ok(tile) = tiles.get(event.tile) else {
+            continue;
+        };
+
+        let coords = tile.coords();
+
+        if grid_index.occupant(coords).is_some() || !grid_index.terrain(coords).is_buildable() {
+            continue;
+        }
+
+        if let Some(index) = roads.tiles.iter().position(|road| *road == coords) {
+            roads.tiles.remove(index);
+        } else if inventory.balance >= ROAD_PRICE {
+            inventory.balance -= ROAD_PRICE;
+            roads.tiles.push(coords);
+        }
+    }
+}
+
+/// Keeps the Road markers and materials of tiles in line with Roads
+fn update_road_tiles(
+    mut commands: Commands,
+    roads: Res<Roads>,
+    tiles: Query<(Entity, &Tile, Option<&Road>)>,
+    tile_materials: Option<Res<TileMaterials>>,
+    grid_index: Res<GridIndex>,
+) {
+    let Some(tile_materials) = tile_materials else {
+        return;
+    };
+
+    if !roads.is_changed() && !tile_materials.is_added() {
+        return;
+    }
+
+    for (entity, tile, road) in tiles.iter() {
+        match (roads.tiles.contains(&tile.coords()), road.is_some()) {
+            (true, false) => {
+                commands
+                    .entity(entity)
+                    .insert(Road)
+                    .insert(tile_materials.road.clone())
+                    .insert(tile_materials.road_highlight());
+            }
+            (false, true) => {
+                commands
+                    .entity(entity)
+                    .remove::<Road>()
+                    .insert(tile_materials.get(grid_index.terrain(tile.coords()), false))
+                    .insert(tile_materials.highlight(grid_index.terrain(tile.coords()), false));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Walks the road network out from the City Centre and flags every building it doesn't reach
+fn update_connectivity(
+    mut commands: Commands,
+    roads: Res<Roads>,
+    grid_index: Res<GridIndex>,
+    buildings: Query<(Entity, &Building, Option<&Disconnected>)>,
+) {
+    if !roads.is_changed() && !grid_index.is_changed() {
+        return;
+    }
+
+    let road_tiles = roads.tiles.iter().copied().collect::<HashSet<(u8, u8)>>();
+
+    // Start from every tile under the City Centre
+    let mut reached = grid_index
+        .occupants()
+        .filter(|(_, entity)| {
+            buildings
+                .get(*entity)
+                .is_ok_and(|(_, building, _)| building.building_type == BuildingType::CityCentre)
+        })
+        .map(|(coords, _)| coords)
+        .collect::<HashSet<(u8, u8)>>();
+
+    let mut queue = reached.iter().copied().collect::<VecDeque<(u8, u8)>>();
+
+    while let Some(coords) = queue.pop_front() {
+        for neighbour in edge_neighbours(coords) {
+            if road_tiles.contains(&neighbour) && reached.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    // Anything touching the City Centre or a reached road is connected
+    let connected = reached
+        .iter()
+        .flat_map(|coords| edge_neighbours(*coords).chain(std::iter::once(*coords)))
+        .filter_map(|coords| grid_index.occupant(coords))
+        .collect::<HashSet<Entity>>();
+
+    for (entity, building, disconnected) in buildings.iter() {
+        // These don't produce anything, so they don't need a road
+        if building.building_type.is_decoration()
+            || matches!(
+                building.building_type,
+                BuildingType::CityCentre | BuildingType::Market | BuildingType::Construct
+            )
+        {
+            continue;
+        }
+
+        match (connected.contains(&entity), disconnected.is_some()) {
+            (true, true) => {
+                commands.entity(entity).remove::<Disconnected>();
+            }
+            (false, false) => {
+                commands.entity(entity).insert(Disconnected);
+            }
+            _ => {}
+        }
+    }
+}
+
+// Marker
+#[derive(Component)]
+struct RoadHintRoot;
+
+fn draw_road_hint(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    keybinds: Res<Keybinds>,
+) {
+    let hint_width = primary_window.single().resolution.width() / 3.0;
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(hint_width), Val::Auto),
+                justify_content: JustifyContent::Center,
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Px((primary_window.single().resolution.width() - hint_width) / 2.0),
+                    bottom: Val::Percent(4.0),
+                    ..default()
+                },
+                padding: UiRect::all(Val::Px(hint_width / 60.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.17, 0.19, 0.36, 0.85).into(),
+            ..default()
+        })
+        .insert(Name::new("Road hint"))
+        .insert(RoadHintRoot)
+        .with_children(|commands| {
+            commands.spawn(TextBundle::from_section(
+                format!(
+                    "Click tiles to lay roads (${ROAD_PRICE}) or take them up - {:?} to finish",
+                    keybinds.toggle_roads
+                ),
+                TextStyle {
+                    font: asset_server.load("font.otf"),
+                    font_size: hint_width / 30.0,
+                    color: Color::WHITE,
+                },
+            ));
+        });
+}
+
+fn undraw_road_hint(mut commands: Commands, ui_root: Query<Entity, With<RoadHintRoot>>) {
+    for entity in ui_root.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
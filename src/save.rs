@@ -0,0 +1,232 @@
+use std::fs;
+
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+const SAVE_PATH: &str = "save.ron";
+// Seconds of game time between saves
+const AUTO_SAVE_PERIOD: f32 = 5.0;
+
+pub struct AutoSavePlugin;
+
+impl Plugin for AutoSavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LoadedSave>()
+            // Runs before the grid is spawned, as which tiles are locked depends on the save
+            .add_startup_system(load_game.in_base_set(StartupSet::PreStartup))
+            .add_startup_system(schedule_auto_save)
+            .add_system(auto_save);
+    }
+}
+
+fn schedule_auto_save(mut scheduler: ResMut<Scheduler>) {
+    scheduler.schedule(Job::AutoSave, AUTO_SAVE_PERIOD);
+}
+
+/// Everything that survives a restart of the game
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct SaveFile {
+    pub balance: u32,
+    pub items: Vec<(ItemType, u32)>,
+    pub construct_stock: Vec<(BuildingType, u8)>,
+    pub buildings: Vec<SavedBuilding>,
+    pub achievements: Achievements,
+    pub quests: Quests,
+    pub bank: Bank,
+    pub ledger: Ledger,
+    pub statistics: Statistics,
+    pub land: Land,
+    pub roads: Roads,
+    pub terrain: Terrain,
+    // Elapsed seconds of every scheduled job
+    pub scheduler: Vec<(Job, f32)>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedBuilding {
+    pub building_type: BuildingType,
+    pub level: u8,
+    pub yields: Vec<(ItemType, u32)>,
+    // Position of the parent tile
+    pub x: f32,
+    pub z: f32,
+    #[serde(default)]
+    pub rotation: u8,
+    // Seconds of construction already done, while it is still being built
+    #[serde(default)]
+    pub construction: Option<f32>,
+}
+
+impl SavedBuilding {
+    /// Grid coordinates of the parent tile - positions are saved in world space
+    pub fn coords(&self) -> (u8, u8) {
+        ((self.x / TILE_SIZE).round() as u8, (self.z / TILE_SIZE).round() as u8)
+    }
+
+    /// Every tile the building's rotated footprint covers
+    pub fn covered(&self) -> Vec<(u8, u8)> {
+        let (i, j) = self.coords();
+        let (width, depth) = self.building_type.get_rotated_footprint(self.rotation);
+
+        (i..i + width)
+            .flat_map(|x| (j..j + depth).map(move |z| (x, z)))
+            .collect()
+    }
+}
+
+impl SaveFile {
+    pub fn read() -> Option<Self> {
+        let contents = fs::read_to_string(SAVE_PATH).ok()?;
+
+        match ron::from_str(&contents) {
+            Ok(save_file) => Some(save_file),
+            Err(err) => {
+                warn!("Ignoring unreadable save file: {err}");
+                None
+            }
+        }
+    }
+
+    pub fn write(&self) {
+        let contents = match ron::ser::to_string_pretty(self, PrettyConfig::default()) {
+            Ok(contents) => contents,
+            Err(err) => {
+                error!("Failed to serialize save file: {err}");
+                return;
+            }
+        };
+
+        if let Err(err) = fs::write(SAVE_PATH, contents) {
+            error!("Failed to write save file: {err}");
+        }
+    }
+}
+
+/// The save file that was read on startup, if any. Buildings are spawned from this once the tiles exist
+#[derive(Resource, Default)]
+pub struct LoadedSave(pub Option<SaveFile>);
+
+#[allow(clippy::complexity)]
+fn load_game(
+    mut loaded_save: ResMut<LoadedSave>,
+    mut inventory: ResMut<Inventory>,
+    mut achievements: ResMut<Achievements>,
+    mut quests: ResMut<Quests>,
+    mut bank: ResMut<Bank>,
+    mut ledger: ResMut<Ledger>,
+    mut statistics: ResMut<Statistics>,
+    mut land: ResMut<Land>,
+    mut roads: ResMut<Roads>,
+    mut terrain: ResMut<Terrain>,
+    mut scheduler: ResMut<Scheduler>,
+) {
+    let Some(save_file) = SaveFile::read() else {
+        debug!("No save file found - starting a new city");
+        return;
+    };
+
+    inventory.balance = save_file.balance;
+
+    for (item_type, quantity) in save_file.items.iter() {
+        if let Some(item) = inventory.items.iter_mut().find(|item| item.item_type == *item_type) {
+            item.quantity = *quantity;
+        }
+    }
+
+    *achievements = save_file.achievements.clone();
+    *quests = save_file.quests.clone();
+    *bank = save_file.bank.clone();
+    *ledger = save_file.ledger.clone();
+    *statistics = save_file.statistics.clone();
+    *land = save_file.land.clone();
+    *roads = save_file.roads.clone();
+    *terrain = save_file.terrain.clone();
+    scheduler.load(save_file.scheduler.clone());
+
+    // Buildings from before land had to be bought keep their tiles
+    for saved_building in save_file.buildings.iter() {
+        let coords = saved_building.coords();
+
+        if !land.owns(coords) {
+            land.owned.push(coords);
+        }
+    }
+
+    debug!("Loaded save file with {} buildings", save_file.buildings.len());
+    loaded_save.0 = Some(save_file);
+}
+
+#[allow(clippy::complexity)]
+pub fn auto_save(
+    scheduler: Res<Scheduler>,
+    inventory: Res<Inventory>,
+    construct_inventory: Res<ConstructInventory>,
+    buildings: Query<(&Building, &Parent, Option<&UnderConstruction>)>,
+    tiles: Query<&Tile>,
+    achievements: Res<Achievements>,
+    quests: Res<Quests>,
+    bank: Res<Bank>,
+    ledger: Res<Ledger>,
+    statistics: Res<Statistics>,
+    land: Res<Land>,
+    roads: Res<Roads>,
+    terrain: Res<Terrain>,
+    setup_state: Res<State<SetupState>>,
+) {
+    // Saving before the buildings are spawned would wipe them from the save file
+    if setup_state.0 != SetupState::SpawnBuildingDone {
+        return;
+    }
+
+    if scheduler.is_due(Job::AutoSave) {
+        SaveFile {
+            balance: inventory.balance,
+            items: inventory
+                .items
+                .iter()
+                .map(|item| (item.item_type, item.quantity))
+                .collect(),
+            construct_stock: construct_inventory
+                .items
+                .iter()
+                .map(|item| (item.building_type, item.quantity))
+                .collect(),
+            buildings: buildings
+                .iter()
+                // These are always spawned fresh
+                .filter(|(building, _, _)| {
+                    !matches!(building.building_type, BuildingType::Market | BuildingType::Construct)
+                })
+                .filter_map(|(building, parent, under_construction)| {
+                    let (x, z) = tiles.get(parent.get()).ok()?.position();
+
+                    Some(SavedBuilding {
+                        building_type: building.building_type,
+                        level: building.level,
+                        yields: building.yields.clone(),
+                        x,
+                        z,
+                        rotation: building.rotation,
+                        construction: under_construction
+                            .map(|under_construction| under_construction.timer.elapsed_secs()),
+                    })
+                })
+                .collect(),
+            achievements: achievements.clone(),
+            quests: quests.clone(),
+            bank: bank.clone(),
+            ledger: ledger.clone(),
+            statistics: statistics.clone(),
+            land: land.clone(),
+            roads: roads.clone(),
+            terrain: terrain.clone(),
+            scheduler: scheduler.save(),
+        }
+        .write();
+
+        debug!("Autosaved");
+    }
+}
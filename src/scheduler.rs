@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// City-wide work that runs on a fixed period rather than belonging to a building
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect, FromReflect)]
+pub enum Job {
+    MarketRestock,
+    BoostRotation,
+    ConstructRestock,
+    BankPayout,
+    Upkeep,
+    CityEventRoll,
+    AutoSave,
+}
+
+impl Job {
+    pub fn get_name(&self) -> &'static str {
+        use Job::*;
+        match self {
+            MarketRestock => "Market restock",
+            BoostRotation => "Boost rotation",
+            ConstructRestock => "Construct restock",
+            BankPayout => "Bank payout",
+            Upkeep => "Upkeep bill",
+            CityEventRoll => "City event roll",
+            AutoSave => "Auto save",
+        }
+    }
+}
+
+#[derive(Reflect, FromReflect)]
+pub struct ScheduledJob {
+    pub job: Job,
+    pub timer: Timer,
+    // Times the job has run since the game started
+    pub runs: u32,
+}
+
+/// Runs every job on its own repeating timer, on game time
+#[derive(Resource, Default, Reflect)]
+pub struct Scheduler {
+    jobs: Vec<ScheduledJob>,
+    // Elapsed seconds read from the save file, picked up as each job is scheduled
+    #[reflect(ignore)]
+    saved: Vec<(Job, f32)>,
+}
+
+impl Scheduler {
+    /// Starts running a job every `period` seconds, carrying on from the save file if it was running before
+    pub fn schedule(&mut self, job: Job, period: f32) {
+        self.schedule_with_elapsed(job, period, 0.0);
+    }
+
+    /// Like `schedule`, but a new game starts `elapsed` seconds into the first period
+    pub fn schedule_with_elapsed(&mut self, job: Job, period: f32, elapsed: f32) {
+        let mut timer = Timer::from_seconds(period, TimerMode::Repeating);
+        let elapsed = self
+            .saved
+            .iter()
+            .find(|(saved_job, _)| *saved_job == job)
+            .map_or(elapsed, |(_, saved_elapsed)| *saved_elapsed);
+
+        timer.set_elapsed(Duration::from_secs_f32(elapsed.clamp(0.0, period)));
+
+        self.jobs.retain(|scheduled_job| scheduled_job.job != job);
+        self.jobs.push(ScheduledJob { job, timer, runs: 0 });
+    }
+
+    fn get(&self, job: Job) -> Option<&ScheduledJob> {
+        self.jobs.iter().find(|scheduled_job| scheduled_job.job == job)
+    }
+
+    /// Whether the job's period came round this frame - enough for jobs where running twice in a
+    /// row does nothing more than running once
+    pub fn is_due(&self, job: Job) -> bool {
+        self.times_due(job) > 0
+    }
+
+    /// How many times the job's period came round this frame, which can be more than once at high
+    /// game speeds
+    pub fn times_due(&self, job: Job) -> u32 {
+        self.get(job)
+            .map_or(0, |scheduled_job| scheduled_job.timer.times_finished_this_tick())
+    }
+
+    pub fn time_remaining(&self, job: Job) -> Option<Duration> {
+        self.get(job).map(|scheduled_job| scheduled_job.timer.remaining())
+    }
+
+    pub fn jobs(&self) -> impl Iterator<Item = &ScheduledJob> {
+        self.jobs.iter()
+    }
+
+    /// Restores how far along each job was - must happen before the jobs are scheduled
+    pub fn load(&mut self, saved: Vec<(Job, f32)>) {
+        self.saved = saved;
+    }
+
+    /// How far along each job is, for the save file
+    pub fn save(&self) -> Vec<(Job, f32)> {
+        self.jobs
+            .iter()
+            .map(|scheduled_job| (scheduled_job.job, scheduled_job.timer.elapsed_secs()))
+            .collect()
+    }
+}
+
+pub struct SchedulerPlugin;
+
+impl Plugin for SchedulerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Scheduler>()
+            .register_type::<Scheduler>()
+            .add_system(tick_scheduler.in_base_set(CoreSet::PreUpdate).after(advance_game_clock));
+    }
+}
+
+/// Ticks every job ahead of Update, so the systems doing the work only have to check `is_due`
+fn tick_scheduler(game_clock: Res<GameClock>, mut scheduler: ResMut<Scheduler>) {
+    for scheduled_job in scheduler.jobs.iter_mut() {
+        scheduled_job.timer.tick(game_clock.delta());
+        scheduled_job.runs += scheduled_job.timer.times_finished_this_tick();
+    }
+}
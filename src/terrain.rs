@@ -0,0 +1,144 @@
+use bevy::utils::HashMap;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+// The terrain is interpolated between random values on a grid this many cells across
+const NOISE_CELLS: usize = 4;
+
+#[derive(Reflect, FromReflect, Debug, PartialEq, Eq, Clone, Copy, Hash, Default, Serialize, Deserialize)]
+pub enum TerrainType {
+    #[default]
+    Grass,
+    Forest,
+    Fertile,
+    Rock,
+    Water,
+}
+
+impl TerrainType {
+    pub fn get_name(&self) -> &'static str {
+        use TerrainType::*;
+
+        match self {
+            Grass => "Grass",
+            Forest => "Forest",
+            Fertile => "Fertile soil",
+            Rock => "Rock",
+            Water => "Water",
+        }
+    }
+
+    /// Tint over the tile texture
+    pub fn get_colour(&self) -> Color {
+        use TerrainType::*;
+
+        match self {
+            Grass => Color::WHITE,
+            Forest => Color::rgb(0.45, 0.7, 0.4),
+            Fertile => Color::rgb(0.75, 0.58, 0.4),
+            Rock => Color::rgb(0.62, 0.62, 0.66),
+            Water => Color::rgb(0.3, 0.5, 0.9),
+        }
+    }
+
+    /// Roads and buildings both need solid ground
+    pub fn is_buildable(&self) -> bool {
+        *self != TerrainType::Water
+    }
+
+    /// Whether a building of this type can stand on this terrain
+    pub fn allows(&self, building_type: BuildingType) -> bool {
+        match self {
+            TerrainType::Water => false,
+            // Nothing takes root on bare rock
+            TerrainType::Rock => !matches!(building_type, BuildingType::Tree | BuildingType::Flowerbed),
+            _ => true,
+        }
+    }
+}
+
+/// The seed the map is generated from, so a city keeps the same ground between sessions
+#[derive(Resource, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct Terrain {
+    pub seed: u64,
+}
+
+impl Default for Terrain {
+    fn default() -> Self {
+        Self { seed: rand::random() }
+    }
+}
+
+impl Terrain {
+    /// Terrain of every tile on a square grid - tiles in `keep_dry` are never water
+    pub fn generate(&self, size: u8, keep_dry: &[(u8, u8)]) -> HashMap<(u8, u8), TerrainType> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let elevation = Noise::new(&mut rng);
+        let moisture = Noise::new(&mut rng);
+        let mut terrain = HashMap::new();
+
+        for i in 0..size {
+            for j in 0..size {
+                let (x, z) = (i as f32 / (size - 1) as f32, j as f32 / (size - 1) as f32);
+                let (height, wetness) = (elevation.sample(x, z), moisture.sample(x, z));
+
+                let terrain_type = if height < 0.3 {
+                    TerrainType::Water
+                } else if height > 0.7 {
+                    TerrainType::Rock
+                } else if wetness > 0.62 {
+                    TerrainType::Forest
+                } else if wetness < 0.38 {
+                    TerrainType::Fertile
+                } else {
+                    TerrainType::Grass
+                };
+
+                let terrain_type = if terrain_type == TerrainType::Water && keep_dry.contains(&(i, j)) {
+                    TerrainType::Grass
+                } else {
+                    terrain_type
+                };
+
+                terrain.insert((i, j), terrain_type);
+            }
+        }
+
+        terrain
+    }
+}
+
+/// Value noise - random corners, smoothly blended in between
+struct Noise {
+    lattice: [[f32; NOISE_CELLS + 1]; NOISE_CELLS + 1],
+}
+
+impl Noise {
+    fn new(rng: &mut StdRng) -> Self {
+        let mut lattice = [[0.0; NOISE_CELLS + 1]; NOISE_CELLS + 1];
+
+        for value in lattice.iter_mut().flatten() {
+            *value = rng.gen();
+        }
+
+        Self { lattice }
+    }
+
+    /// A value in 0..1 for a point in 0..1 along both axes
+    fn sample(&self, x: f32, z: f32) -> f32 {
+        let (x, z) = (x * NOISE_CELLS as f32, z * NOISE_CELLS as f32);
+        let (i, j) = ((x as usize).min(NOISE_CELLS - 1), (z as usize).min(NOISE_CELLS - 1));
+        let smooth = |t: f32| t * t * (3.0 - 2.0 * t);
+        let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+        let (tx, tz) = (smooth(x - i as f32), smooth(z - j as f32));
+
+        lerp(
+            lerp(self.lattice[i][j], self.lattice[i + 1][j], tx),
+            lerp(self.lattice[i][j + 1], self.lattice[i + 1][j + 1], tx),
+            tz,
+        )
+    }
+}
@@ -10,51 +10,150 @@ pub struct Timers {
     map: HashMap<Entity, Timer>,
 }
 
+/// Shortest production cycle a timer runs at, in seconds - a zero length repeating timer never finishes
+pub const MIN_CYCLE_SECONDS: f32 = 0.1;
+
+fn cycle_duration(speed: f32) -> Duration {
+    Duration::from_secs_f32(speed.max(MIN_CYCLE_SECONDS))
+}
+
 impl Timers {
-    pub fn add_timer(&mut self, entity: Entity, speed: u8) {
-        let mut timer = Timer::new(Duration::from_secs(speed.into()), TimerMode::Repeating);
+    /// Starts a repeating timer that finishes every `speed` seconds
+    pub fn add_timer(&mut self, entity: Entity, speed: f32) {
+        let duration = cycle_duration(speed);
+        let mut timer = Timer::new(duration, TimerMode::Repeating);
         // Allow the timer to run once almost instantly on spawn - its just so much nicer this way
-        timer.set_elapsed(Duration::from_secs((speed - 1).into()));
+        timer.set_elapsed(duration.saturating_sub(Duration::from_secs(1)));
         self.map.insert(entity, timer);
     }
 
-    pub fn update_timer_speed(&mut self, entity: &Entity, speed: u8) {
-        self.map
-            .get_mut(entity)
-            .unwrap()
-            .set_duration(Duration::from_secs(speed.into()));
+    pub fn update_timer_speed(&mut self, entity: &Entity, speed: f32) {
+        if let Some(timer) = self.map.get_mut(entity) {
+            timer.set_duration(cycle_duration(speed));
+        }
+    }
+
+    pub fn remove_timer(&mut self, entity: &Entity) {
+        self.map.remove(entity);
+    }
+
+    pub fn time_remaining(&self, entity: &Entity) -> Option<Duration> {
+        self.map.get(entity).map(|timer| timer.remaining())
+    }
+
+    /// How far through its current cycle a timer is, from 0 to 1
+    pub fn progress(&self, entity: &Entity) -> Option<f32> {
+        self.map.get(entity).map(|timer| timer.percent())
     }
 }
 
+// Seconds between Market restocks
+const MARKET_RESTOCK_PERIOD: f32 = 30.0;
+// Seconds between changes of which items sell for double
+const BOOST_ROTATION_PERIOD: f32 = 30.0;
+
 pub struct TimerPlugin;
 
 impl Plugin for TimerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<RestockMarketEvent>()
-            .add_event::<AfterBoostUIEvent>()
+        app.add_event::<AfterBoostUIEvent>()
             .init_resource::<Timers>()
-            .add_systems((tick_timers, restock_market, after_boost_ui));
+            .add_startup_system(schedule_market_jobs)
+            .add_systems((tick_timers, restock_market, rotate_boosts, after_boost_ui));
     }
 }
 
-struct RestockMarketEvent;
+fn schedule_market_jobs(mut scheduler: ResMut<Scheduler>) {
+    // A new city gets its first restock and boosts a second in
+    scheduler.schedule_with_elapsed(Job::MarketRestock, MARKET_RESTOCK_PERIOD, MARKET_RESTOCK_PERIOD - 1.0);
+    scheduler.schedule_with_elapsed(Job::BoostRotation, BOOST_ROTATION_PERIOD, BOOST_ROTATION_PERIOD - 1.0);
+}
 
 fn restock_market(
+    scheduler: Res<Scheduler>,
     mut market_inventory: ResMut<MarketInventory>,
-    mut restock_market_events: EventReader<RestockMarketEvent>,
+    modifiers: Res<Modifiers>,
     previous_camera_state: Res<PreviousCameraState>,
     mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
     mut next_ui_state: ResMut<NextState<UiState>>,
     ui_state: Res<State<UiState>>,
 ) {
-    for _ in restock_market_events.iter() {
-        *market_inventory = MarketInventory::default();
+    if !scheduler.is_due(Job::MarketRestock) {
+        return;
+    }
 
-        if ui_state.0 == UiState::Market {
-            next_ui_state.set(UiState::None);
-            send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+    *market_inventory = MarketInventory::default();
+
+    for item in market_inventory.items.iter_mut() {
+        if modifiers.is_short(item.item_type) {
+            item.quantity = 0;
         }
     }
+
+    if ui_state.0 == UiState::Market {
+        next_ui_state.set(UiState::None);
+        send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+    }
+}
+
+/// Picks new items to sell for double, dropping the old boosts
+fn rotate_boosts(
+    scheduler: Res<Scheduler>,
+    mut inventory: ResMut<Inventory>,
+    mut selected_item_stats: ResMut<SelectedItemStats>,
+    modifiers: Res<Modifiers>,
+    mut send_after_boost_ui_event: EventWriter<AfterBoostUIEvent>,
+) {
+    if !scheduler.is_due(Job::BoostRotation) {
+        return;
+    }
+
+    // Reset boosts and sell price
+    for item in inventory.items.iter_mut() {
+        item.boosted = false;
+        item.sell_price = Item::get_sell_price(item.item_type);
+
+        // If the user stays on the same item, drop the boost
+        if let Some(item_type) = selected_item_stats.item_type {
+            if item_type == item.item_type {
+                selected_item_stats.sell_price = modifiers.sell_price(item_type, item.sell_price);
+            }
+        }
+    }
+
+    let mut rng = rand::thread_rng();
+
+    // Choose 5 random item types to boost
+    let random_types = inventory
+        .items
+        .iter()
+        .map(|item| item.item_type)
+        .choose_multiple(&mut rng, 5);
+
+    for item in inventory.items.iter_mut() {
+        if random_types.contains(&item.item_type) {
+            item.boosted = true;
+            item.sell_price = Item::get_sell_price(item.item_type) * 2;
+        }
+    }
+
+    // If the user stays on the same item, manifest the boost
+    if let Some(item_type) = selected_item_stats.item_type {
+        if random_types.contains(&item_type) {
+            let sell_price = inventory
+                .items
+                .iter()
+                .find(|item| item.item_type == item_type)
+                .unwrap()
+                .sell_price;
+            selected_item_stats.sell_price = modifiers.sell_price(item_type, sell_price);
+        }
+    }
+
+    // Send event to update all required UI components
+    send_after_boost_ui_event.send(AfterBoostUIEvent {
+        boosted_items: random_types,
+    });
 }
 
 struct AfterBoostUIEvent {
@@ -88,27 +187,63 @@ fn after_boost_ui(
 #[allow(clippy::complexity)]
 fn tick_timers(
     mut timers: ResMut<Timers>,
-    mut buildings: Query<(Entity, &mut Building)>,
-    time: Res<Time>,
+    mut buildings: Query<(
+        Entity,
+        &mut Building,
+        Option<&Synergies>,
+        Option<&Disconnected>,
+        Option<&UnderConstruction>,
+    )>,
+    game_clock: Res<GameClock>,
     upgrade_data: Res<UpgradeData>,
     mut yield_stats_text: Query<(&mut Text, &YieldCountText)>,
     selected_building: ResMut<SelectedBuilding>,
-    mut send_restock_market_event: EventWriter<RestockMarketEvent>,
-    mut send_after_boost_ui_event: EventWriter<AfterBoostUIEvent>,
-    mut inventory: ResMut<Inventory>,
-    mut selected_item_stats: ResMut<SelectedItemStats>,
+    achievements: Res<Achievements>,
+    achievement_data: Res<AchievementData>,
+    ledger: Res<Ledger>,
+    upkeep_data: Res<UpkeepData>,
+    modifiers: Res<Modifiers>,
+    mut send_game_event: EventWriter<GameEvent>,
 ) {
+    let yield_bonus = achievements.yield_bonus(&achievement_data);
+    let production_percent = ledger.production_percent(&upkeep_data);
+
     for (entity, timer) in timers.map.iter_mut() {
-        timer.tick(time.delta());
+        // Buildings only start producing once they are built
+        if buildings
+            .get(*entity)
+            .is_ok_and(|(.., under_construction)| under_construction.is_some())
+        {
+            continue;
+        }
+
+        // Neighbours can make a building cycle faster
+        let speed_multiplier = buildings
+            .get(*entity)
+            .ok()
+            .and_then(|(_, _, synergies, _, _)| synergies)
+            .map_or(1.0, |synergies| synergies.speed_multiplier());
+
+        timer.tick(game_clock.delta().mul_f32(speed_multiplier));
+
+        // Short cycles can finish more than once in a single frame
+        let cycles = timer.times_finished_this_tick();
 
-        if timer.just_finished() {
+        if cycles > 0 {
             // get the building
 
             let mut target_building = None;
+            let mut target_synergies = None;
+            let mut road_percent = 100;
 
-            for (building_entity, building) in buildings.iter_mut() {
+            for (building_entity, building, synergies, disconnected, _) in buildings.iter_mut() {
                 if *entity == building_entity {
-                    target_building = Some(building)
+                    target_building = Some(building);
+                    target_synergies = synergies;
+
+                    if disconnected.is_some() {
+                        road_percent = DISCONNECTED_PRODUCTION;
+                    }
                 }
             }
 
@@ -116,82 +251,57 @@ fn tick_timers(
                 return;
             }
 
-            match target_building.as_ref().unwrap().building_type {
-                BuildingType::Market => {
-                    send_restock_market_event.send(RestockMarketEvent);
-                }
-                // The fact that the type is Construct has no semantic meaning - it's only being
-                // used because CityCentre is already occupied with another timer
-                BuildingType::Construct => {
-                    // Reset boosts and sell price
-                    for item in inventory.items.iter_mut() {
-                        item.boosted = false;
-                        item.sell_price = Item::get_sell_price(item.item_type);
-
-                        // If the user stays on the same item, drop the boost
-                        if let Some(item_type) = selected_item_stats.item_type {
-                            if item_type == item.item_type {
-                                selected_item_stats.sell_price = item.sell_price;
-                            }
-                        }
-                    }
-
-                    let mut rng = rand::thread_rng();
+            // Add items to the building's yield
+            let mut building = target_building.unwrap();
+            // let mut yields = &mut building.yields;
+            let add = upgrade_data.map[&building.building_type][&building.level].yields;
+            let yield_percent = modifiers.yield_percent(building.building_type);
+            let mut produced = vec![];
 
-                    // Choose 5 random item types to boost
-                    let random_types = inventory
-                        .items
-                        .iter()
-                        .map(|item| item.item_type)
-                        .choose_multiple(&mut rng, 5);
+            for (item_type, qty) in building.yields.iter_mut() {
+                for item in add {
+                    if item.0 == *item_type {
+                        let adjacency_bonus = target_synergies.map_or(0, |synergies| synergies.yield_bonus(*item_type));
+                        let base = apply_percent_bonus(item.1 as u32, yield_bonus + adjacency_bonus);
+                        let quantity =
+                            scale_by_percents(base, &[production_percent, yield_percent, road_percent]) * cycles;
 
-                    for item in inventory.items.iter_mut() {
-                        if random_types.contains(&item.item_type) {
-                            item.boosted = true;
-                            item.sell_price = Item::get_sell_price(item.item_type) * 2;
-                        }
-                    }
+                        *qty += quantity;
 
-                    // If the user stays on the same item, manifest the boost
-                    if let Some(item_type) = selected_item_stats.item_type {
-                        if random_types.contains(&item_type) {
-                            selected_item_stats.sell_price = inventory
-                                .items
-                                .iter()
-                                .find(|item| item.item_type == item_type)
-                                .unwrap()
-                                .sell_price;
+                        if quantity > 0 {
+                            produced.push((*item_type, quantity));
                         }
                     }
-
-                    // Send event to update all required UI components
-                    send_after_boost_ui_event.send(AfterBoostUIEvent {
-                        boosted_items: random_types,
-                    });
                 }
-                _ => {
-                    // Add items to the building's yield
-                    let mut building = target_building.unwrap();
-                    // let mut yields = &mut building.yields;
-                    let add = upgrade_data.map[&building.building_type][&building.level].yields;
-
-                    for (item_type, qty) in building.yields.iter_mut() {
-                        for item in add {
-                            if item.0 == *item_type {
-                                *qty += item.1 as u32;
-                            }
-                        }
-                    }
+            }
 
-                    if selected_building.building == Some(*entity) {
-                        for (mut text, YieldCountText { position }) in yield_stats_text.iter_mut() {
-                            text.sections[0].value = format!("x{}", building.yields[*position].1);
-                        }
-                    }
+            send_game_event.send(GameEvent::Produced {
+                entity: *entity,
+                building_type: building.building_type,
+                items: produced,
+            });
+
+            if selected_building.building == Some(*entity) {
+                for (mut text, YieldCountText { position }) in yield_stats_text.iter_mut() {
+                    text.sections[0].value = format!("x{}", building.yields[*position].1);
                 }
             }
-
-            // match the building type and action from there
         }
     }
 }
+
+/// Scales a value by several percentages at once, rounding only at the end so small yields
+/// aren't truncated away by each penalty in turn. Penalties never stop a yield outright - only a
+/// 0% modifier does
+fn scale_by_percents(value: u32, percents: &[u32]) -> u32 {
+    let share = percents
+        .iter()
+        .fold(1.0, |share, percent| share * *percent as f32 / 100.0);
+    let scaled = (value as f32 * share).round() as u32;
+
+    if value > 0 && share > 0.0 {
+        scaled.max(1)
+    } else {
+        scaled
+    }
+}
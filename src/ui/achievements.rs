@@ -0,0 +1,422 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+#[derive(Resource, Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct Achievements {
+    pub counters: AchievementCounters,
+    pub unlocked: Vec<UnlockedAchievement>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct AchievementCounters {
+    pub upgrades: u32,
+    pub items_collected: u32,
+    pub items_sold: u32,
+    pub items_bought: u32,
+    pub constructions: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UnlockedAchievement {
+    pub id: String,
+    // Seconds since the unix epoch
+    pub unlocked_at: u64,
+}
+
+impl Achievements {
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.iter().any(|unlocked| unlocked.id == id)
+    }
+
+    pub fn sell_price_bonus(&self, achievement_data: &AchievementData) -> u32 {
+        self.unlocked_bonuses(achievement_data)
+            .map(|bonus| match bonus {
+                AchievementBonus::SellPrice(percent) => percent,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    pub fn yield_bonus(&self, achievement_data: &AchievementData) -> u32 {
+        self.unlocked_bonuses(achievement_data)
+            .map(|bonus| match bonus {
+                AchievementBonus::Yield(percent) => percent,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn unlocked_bonuses<'a>(
+        &'a self,
+        achievement_data: &'a AchievementData,
+    ) -> impl Iterator<Item = AchievementBonus> + 'a {
+        achievement_data
+            .achievements
+            .iter()
+            .filter(|achievement| self.is_unlocked(achievement.id))
+            .filter_map(|achievement| achievement.bonus)
+    }
+}
+
+/// Adds a percentage on top of a value, rounding to the nearest whole number. Saturates at
+/// `u32::MAX` so compounding values like savings can't overflow
+pub fn apply_percent_bonus(value: u32, percent: u32) -> u32 {
+    let bonused = (value as u64 * (100 + percent as u64) + 50) / 100;
+
+    u32::try_from(bonused).unwrap_or(u32::MAX)
+}
+
+/// Returns (progress, target) for an achievement goal
+fn goal_progress(
+    goal: &AchievementGoal,
+    achievements: &Achievements,
+    balance: u32,
+    buildings: &[&Building],
+    upgrade_data: &UpgradeData,
+) -> (u32, u32) {
+    let counters = &achievements.counters;
+
+    match goal {
+        AchievementGoal::Balance(target) => (balance, *target),
+        AchievementGoal::MaxLevel(building_type) => {
+            let max_level = upgrade_data.map[building_type].keys().max().copied().unwrap_or(1);
+            let highest_level = buildings
+                .iter()
+                .filter(|building| building.building_type == *building_type)
+                .map(|building| building.level)
+                .max()
+                .unwrap_or(0);
+
+            (highest_level as u32, max_level as u32)
+        }
+        AchievementGoal::Own(building_type, target) => {
+            let owned = buildings
+                .iter()
+                .filter(|building| building.building_type == *building_type)
+                .count();
+
+            (owned as u32, *target)
+        }
+        AchievementGoal::Upgrades(target) => (counters.upgrades, *target),
+        AchievementGoal::ItemsCollected(target) => (counters.items_collected, *target),
+        AchievementGoal::ItemsSold(target) => (counters.items_sold, *target),
+        AchievementGoal::ItemsBought(target) => (counters.items_bought, *target),
+        AchievementGoal::Constructions(target) => (counters.constructions, *target),
+    }
+}
+
+pub struct AchievementPlugin;
+
+impl Plugin for AchievementPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Achievements>()
+            .add_systems((count_game_events, check_achievements).chain())
+            .add_system(toggle_achievements)
+            .add_system(draw_achievements.in_schedule(OnEnter(UiState::Achievements)))
+            .add_system(undraw_achievements.in_schedule(OnExit(UiState::Achievements)));
+    }
+}
+
+fn count_game_events(mut game_events: EventReader<GameEvent>, mut achievements: ResMut<Achievements>) {
+    for event in game_events.iter() {
+        let counters = &mut achievements.counters;
+
+        match event {
+            GameEvent::Upgraded { .. } => counters.upgrades += 1,
+            GameEvent::Collected { items, .. } => {
+                counters.items_collected += items.iter().map(|(_, quantity)| quantity).sum::<u32>()
+            }
+            GameEvent::Sold { quantity, .. } => counters.items_sold += quantity,
+            GameEvent::Bought { quantity, .. } => counters.items_bought += quantity,
+            GameEvent::Constructed { .. } => counters.constructions += 1,
+            GameEvent::Produced { .. } | GameEvent::LandBought { .. } | GameEvent::Demolished { .. } => {}
+        }
+    }
+}
+
+fn check_achievements(
+    mut achievements: ResMut<Achievements>,
+    achievement_data: Res<AchievementData>,
+    upgrade_data: Res<UpgradeData>,
+    inventory: Res<Inventory>,
+    buildings: Query<&Building>,
+) {
+    let buildings = buildings.iter().collect::<Vec<&Building>>();
+
+    for achievement in achievement_data.achievements.iter() {
+        if achievements.is_unlocked(achievement.id) {
+            continue;
+        }
+
+        let (progress, target) = goal_progress(
+            &achievement.goal,
+            &achievements,
+            inventory.balance,
+            &buildings,
+            &upgrade_data,
+        );
+
+        if progress >= target {
+            info!("Achievement unlocked: {}", achievement.name);
+
+            let unlocked_at = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default();
+
+            achievements.unlocked.push(UnlockedAchievement {
+                id: achievement.id.to_string(),
+                unlocked_at,
+            });
+        }
+    }
+}
+
+#[allow(clippy::complexity)]
+fn toggle_achievements(
+    keybinds: Res<Keybinds>,
+    keys: Res<Input<KeyCode>>,
+    camera_state: Res<State<CameraState>>,
+    mut previous_camera_state: ResMut<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    ui_state: Res<State<UiState>>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+    construct_state: Res<State<ConstructPhase>>,
+) {
+    if construct_state.0 != ConstructPhase::Normal {
+        return;
+    }
+
+    if ui_state.0 == UiState::Achievements
+        && (keys.just_pressed(keybinds.toggle_achievements) || keys.just_pressed(keybinds.exit_uistate))
+    {
+        next_ui_state.set(UiState::None);
+        send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+    } else if ui_state.0 == UiState::None && keys.just_pressed(keybinds.toggle_achievements) {
+        previous_camera_state.0 = Some(camera_state.0.clone());
+        send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::Frozen));
+        next_ui_state.set(UiState::Achievements);
+    }
+}
+
+// Marker
+#[derive(Component)]
+struct AchievementsUIRoot;
+
+#[allow(clippy::complexity)]
+fn draw_achievements(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    achievements: Res<Achievements>,
+    achievement_data: Res<AchievementData>,
+    upgrade_data: Res<UpgradeData>,
+    inventory: Res<Inventory>,
+    buildings: Query<&Building>,
+) {
+    // UI takes up half the screen & renders at a ratio of 1:1.777
+    let mut ui_width = primary_window.single().resolution.width() / 2.0;
+    let mut ui_height = ui_width / (1920.0 / 1080.0);
+
+    if ui_height > primary_window.single().resolution.height() {
+        ui_height = primary_window.single().resolution.height() / 2.0;
+        ui_width = ui_height * (1920.0 / 1080.0);
+    }
+
+    let buildings = buildings.iter().collect::<Vec<&Building>>();
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.9).into(),
+            ..default()
+        })
+        .insert(Name::new("Achievements UI Root"))
+        .insert(AchievementsUIRoot)
+        .with_children(|commands| {
+            commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(ui_width), Val::Px(ui_height)),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::SpaceEvenly,
+                        align_items: AlignItems::Center,
+                        align_self: AlignSelf::Center,
+                        margin: UiRect::left(Val::Px(
+                            // Offset required for the centre of the UI width to align with centre of screen
+                            (primary_window.single().resolution.width() - ui_width) / 2.0,
+                        )),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.13, 0.14, 0.26).into(),
+                    ..default()
+                })
+                .insert(Name::new("Layout"))
+                .with_children(|commands| {
+                    commands.spawn(TextBundle::from_section(
+                        format!(
+                            "Achievements ({}/{})",
+                            achievements.unlocked.len(),
+                            achievement_data.achievements.len()
+                        ),
+                        TextStyle {
+                            font: asset_server.load("font.otf"),
+                            font_size: ui_width / 30.0,
+                            color: Color::WHITE,
+                        },
+                    ));
+
+                    for achievement in achievement_data.achievements.iter() {
+                        let (progress, target) = goal_progress(
+                            &achievement.goal,
+                            &achievements,
+                            inventory.balance,
+                            &buildings,
+                            &upgrade_data,
+                        );
+
+                        let unlocked = achievements
+                            .unlocked
+                            .iter()
+                            .find(|unlocked| unlocked.id == achievement.id);
+
+                        let fraction = if unlocked.is_some() {
+                            1.0
+                        } else {
+                            (progress as f32 / target.max(1) as f32).min(1.0)
+                        };
+
+                        let description = match achievement.bonus {
+                            Some(AchievementBonus::SellPrice(percent)) => {
+                                format!("{} (+{percent}% sell price)", achievement.description)
+                            }
+                            Some(AchievementBonus::Yield(percent)) => {
+                                format!("{} (+{percent}% yields)", achievement.description)
+                            }
+                            None => achievement.description.to_string(),
+                        };
+
+                        let status = match unlocked {
+                            Some(unlocked) => format!("Unlocked {}", format_date(unlocked.unlocked_at)),
+                            None => format!("{}/{}", progress.min(target), target),
+                        };
+
+                        commands
+                            .spawn(NodeBundle {
+                                style: Style {
+                                    size: Size::new(Val::Percent(90.0), Val::Percent(8.0)),
+                                    flex_direction: FlexDirection::Row,
+                                    justify_content: JustifyContent::SpaceBetween,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                background_color: Color::rgb(0.17, 0.19, 0.36).into(),
+                                ..default()
+                            })
+                            .insert(Name::new(format!("Achievement {}", achievement.id)))
+                            .with_children(|commands| {
+                                // Name and description
+                                commands
+                                    .spawn(NodeBundle {
+                                        style: Style {
+                                            size: Size::new(Val::Percent(55.0), Val::Percent(100.0)),
+                                            flex_direction: FlexDirection::Column,
+                                            justify_content: JustifyContent::Center,
+                                            margin: UiRect::left(Val::Percent(2.0)),
+                                            ..default()
+                                        },
+                                        ..default()
+                                    })
+                                    .with_children(|commands| {
+                                        commands.spawn(TextBundle::from_section(
+                                            achievement.name,
+                                            TextStyle {
+                                                font: asset_server.load("font.otf"),
+                                                font_size: ui_width / 45.0,
+                                                color: if unlocked.is_some() { Color::GREEN } else { Color::WHITE },
+                                            },
+                                        ));
+                                        commands.spawn(TextBundle::from_section(
+                                            description,
+                                            TextStyle {
+                                                font: asset_server.load("font.otf"),
+                                                font_size: ui_width / 60.0,
+                                                color: Color::GRAY,
+                                            },
+                                        ));
+                                    });
+
+                                // Progress bar
+                                commands
+                                    .spawn(NodeBundle {
+                                        style: Style {
+                                            size: Size::new(Val::Percent(40.0), Val::Percent(60.0)),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            margin: UiRect::right(Val::Percent(2.0)),
+                                            ..default()
+                                        },
+                                        background_color: Color::rgb(0.22, 0.25, 0.48).into(),
+                                        ..default()
+                                    })
+                                    .with_children(|commands| {
+                                        commands.spawn(NodeBundle {
+                                            style: Style {
+                                                size: Size::new(Val::Percent(fraction * 100.0), Val::Percent(100.0)),
+                                                position_type: PositionType::Absolute,
+                                                position: UiRect::left(Val::Percent(0.0)),
+                                                ..default()
+                                            },
+                                            background_color: Color::rgb(0.55, 0.44, 0.95).into(),
+                                            ..default()
+                                        });
+
+                                        commands.spawn(TextBundle::from_section(
+                                            status,
+                                            TextStyle {
+                                                font: asset_server.load("font.otf"),
+                                                font_size: ui_width / 60.0,
+                                                color: Color::WHITE,
+                                            },
+                                        ));
+                                    });
+                            });
+                    }
+                });
+        });
+}
+
+fn undraw_achievements(mut commands: Commands, ui_root: Query<Entity, With<AchievementsUIRoot>>) {
+    for entity in ui_root.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Formats a unix timestamp as YYYY-MM-DD (UTC)
+fn format_date(timestamp: u64) -> String {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = (timestamp / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
@@ -0,0 +1,475 @@
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// The player's savings and loan at the City Centre bank
+#[derive(Resource, Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct Bank {
+    pub savings: u32,
+    pub loan: Option<Loan>,
+    // Each default makes every future loan more expensive
+    pub defaults: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Loan {
+    /// Outstanding debt, including interest and late fees
+    pub owed: u32,
+    pub installment: u32,
+    pub missed_payments: u32,
+}
+
+impl Bank {
+    /// Interest for a loan offer, including the penalty for past defaults
+    pub fn loan_interest(&self, offer: &LoanOffer, bank_data: &BankData) -> u32 {
+        offer.interest + self.defaults * bank_data.default_interest_penalty
+    }
+
+    pub fn take_loan(&mut self, offer: &LoanOffer, bank_data: &BankData, inventory: &mut Inventory) {
+        if self.loan.is_some() {
+            return;
+        }
+
+        let owed = apply_percent_bonus(offer.amount, self.loan_interest(offer, bank_data));
+
+        self.loan = Some(Loan {
+            owed,
+            // Round up so the last repayment is never larger than the rest
+            installment: owed.div_ceil(offer.installments),
+            missed_payments: 0,
+        });
+        inventory.balance += offer.amount;
+    }
+
+    pub fn repay_loan(&mut self, inventory: &mut Inventory) {
+        let Some(loan) = &self.loan else {
+            return;
+        };
+
+        if inventory.balance >= loan.owed {
+            inventory.balance -= loan.owed;
+            self.loan = None;
+        }
+    }
+}
+
+pub struct BankPlugin;
+
+impl Plugin for BankPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Bank>()
+            .add_startup_system(schedule_bank_payout)
+            .add_system(tick_bank)
+            .add_system(draw_bank.in_schedule(OnEnter(UiState::Bank)))
+            .add_system(undraw_bank.in_schedule(OnExit(UiState::Bank)))
+            .add_systems((exit_uistate, update_bank_text, bank_button_interaction).in_set(OnUpdate(UiState::Bank)));
+    }
+}
+
+fn schedule_bank_payout(mut scheduler: ResMut<Scheduler>, bank_data: Res<BankData>) {
+    scheduler.schedule(Job::BankPayout, bank_data.period);
+}
+
+fn tick_bank(
+    scheduler: Res<Scheduler>,
+    bank_data: Res<BankData>,
+    mut bank: ResMut<Bank>,
+    mut inventory: ResMut<Inventory>,
+) {
+    for _ in 0..scheduler.times_due(Job::BankPayout) {
+        pay_out(&bank_data, &mut bank, &mut inventory);
+    }
+}
+
+/// One period of savings interest and loan repayment
+fn pay_out(bank_data: &BankData, bank: &mut Bank, inventory: &mut Inventory) {
+    if bank.savings > 0 {
+        bank.savings = apply_percent_bonus(bank.savings, bank_data.savings_interest);
+    }
+
+    let Some(loan) = bank.loan.as_mut() else {
+        return;
+    };
+
+    let payment = loan.installment.min(loan.owed);
+
+    if inventory.balance >= payment {
+        inventory.balance -= payment;
+        loan.owed -= payment;
+        loan.missed_payments = 0;
+
+        if loan.owed == 0 {
+            info!("Loan repaid");
+            bank.loan = None;
+        }
+
+        return;
+    }
+
+    loan.missed_payments += 1;
+    loan.owed += payment * bank_data.late_fee / 100;
+    warn!("Missed a loan repayment of ${payment}");
+
+    if loan.missed_payments >= bank_data.max_missed_payments {
+        // The bank takes what it can - savings first, then cash - and writes off the rest
+        let mut owed = loan.owed;
+
+        let from_savings = bank.savings.min(owed);
+        bank.savings -= from_savings;
+        owed -= from_savings;
+
+        let from_balance = inventory.balance.min(owed);
+        inventory.balance -= from_balance;
+
+        bank.loan = None;
+        bank.defaults += 1;
+        warn!("Defaulted on a loan - seized ${}", from_savings + from_balance);
+    }
+}
+
+fn exit_uistate(
+    keybinds: Res<Keybinds>,
+    keys: Res<Input<KeyCode>>,
+    previous_camera_state: Res<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+) {
+    if !keys.just_pressed(keybinds.exit_uistate) {
+        return;
+    }
+
+    next_ui_state.set(UiState::None);
+    send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+}
+
+// Marker
+#[derive(Component)]
+struct BankUIRoot;
+
+// Marker
+#[derive(Component)]
+struct SavingsText;
+
+// Marker
+#[derive(Component)]
+struct LoanText;
+
+#[derive(Component, Clone, Copy)]
+enum BankButton {
+    // None deposits the whole balance
+    Deposit(Option<u32>),
+    WithdrawAll,
+    // Index into BankData.loan_offers
+    Borrow(usize),
+    Repay,
+}
+
+fn savings_text(bank: &Bank, inventory: &Inventory, bank_data: &BankData) -> String {
+    format!(
+        "Savings: ${}\nCash: ${}\nEarns {}% every {}s",
+        bank.savings, inventory.balance, bank_data.savings_interest, bank_data.period
+    )
+}
+
+fn loan_text(bank: &Bank, bank_data: &BankData, scheduler: &Scheduler) -> String {
+    let mut text = match &bank.loan {
+        Some(loan) => format!(
+            "Owed: ${}\nNext repayment of ${} in {}s\nMissed repayments: {}/{}",
+            loan.owed,
+            loan.installment.min(loan.owed),
+            scheduler
+                .time_remaining(Job::BankPayout)
+                .map_or(0, |remaining| remaining.as_secs() + 1),
+            loan.missed_payments,
+            bank_data.max_missed_payments
+        ),
+        None => "No active loan".to_string(),
+    };
+
+    if bank.defaults > 0 {
+        text.push_str(&format!(
+            "\nDefaults: {} (+{}% interest)",
+            bank.defaults,
+            bank.defaults * bank_data.default_interest_penalty
+        ));
+    }
+
+    text
+}
+
+#[allow(clippy::complexity)]
+fn draw_bank(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    bank: Res<Bank>,
+    bank_data: Res<BankData>,
+    scheduler: Res<Scheduler>,
+    inventory: Res<Inventory>,
+) {
+    // UI takes up half the screen & renders at a ratio of 1:1.777
+    let mut ui_width = primary_window.single().resolution.width() / 2.0;
+    let mut ui_height = ui_width / (1920.0 / 1080.0);
+
+    if ui_height > primary_window.single().resolution.height() {
+        ui_height = primary_window.single().resolution.height() / 2.0;
+        ui_width = ui_height * (1920.0 / 1080.0);
+    }
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.9).into(),
+            ..default()
+        })
+        .insert(Name::new("Bank UI Root"))
+        .insert(BankUIRoot)
+        .with_children(|commands| {
+            commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(ui_width), Val::Px(ui_height)),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceEvenly,
+                        align_items: AlignItems::Center,
+                        align_self: AlignSelf::Center,
+                        margin: UiRect::left(Val::Px(
+                            // Offset required for the centre of the UI width to align with centre of screen
+                            (primary_window.single().resolution.width() - ui_width) / 2.0,
+                        )),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.13, 0.14, 0.26).into(),
+                    ..default()
+                })
+                .insert(Name::new("Layout"))
+                .with_children(|commands| {
+                    // Savings
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(45.0), Val::Percent(90.0)),
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: Color::rgb(0.17, 0.19, 0.36).into(),
+                            ..default()
+                        })
+                        .insert(Name::new("Savings container"))
+                        .with_children(|commands| {
+                            spawn_heading(commands, &asset_server, "Savings", ui_width);
+
+                            commands
+                                .spawn(TextBundle {
+                                    style: Style {
+                                        margin: UiRect::all(Val::Percent(4.0)),
+                                        ..default()
+                                    },
+                                    text: Text::from_section(
+                                        savings_text(&bank, &inventory, &bank_data),
+                                        TextStyle {
+                                            font: asset_server.load("font.otf"),
+                                            font_size: ui_width / 45.0,
+                                            color: Color::WHITE,
+                                        },
+                                    ),
+                                    ..default()
+                                })
+                                .insert(SavingsText);
+
+                            spawn_bank_button(
+                                commands,
+                                &asset_server,
+                                "Deposit $1000",
+                                BankButton::Deposit(Some(1_000)),
+                                ui_width,
+                            );
+                            spawn_bank_button(
+                                commands,
+                                &asset_server,
+                                "Deposit all",
+                                BankButton::Deposit(None),
+                                ui_width,
+                            );
+                            spawn_bank_button(
+                                commands,
+                                &asset_server,
+                                "Withdraw all",
+                                BankButton::WithdrawAll,
+                                ui_width,
+                            );
+                        });
+
+                    // Loans
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(45.0), Val::Percent(90.0)),
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: Color::rgb(0.17, 0.19, 0.36).into(),
+                            ..default()
+                        })
+                        .insert(Name::new("Loan container"))
+                        .with_children(|commands| {
+                            spawn_heading(commands, &asset_server, "Loans", ui_width);
+
+                            commands
+                                .spawn(TextBundle {
+                                    style: Style {
+                                        margin: UiRect::all(Val::Percent(4.0)),
+                                        ..default()
+                                    },
+                                    text: Text::from_section(
+                                        loan_text(&bank, &bank_data, &scheduler),
+                                        TextStyle {
+                                            font: asset_server.load("font.otf"),
+                                            font_size: ui_width / 45.0,
+                                            color: Color::WHITE,
+                                        },
+                                    ),
+                                    ..default()
+                                })
+                                .insert(LoanText);
+
+                            for (index, offer) in bank_data.loan_offers.iter().enumerate() {
+                                spawn_bank_button(
+                                    commands,
+                                    &asset_server,
+                                    &format!(
+                                        "Borrow ${} at {}% over {} repayments",
+                                        offer.amount,
+                                        bank.loan_interest(offer, &bank_data),
+                                        offer.installments
+                                    ),
+                                    BankButton::Borrow(index),
+                                    ui_width,
+                                );
+                            }
+
+                            spawn_bank_button(commands, &asset_server, "Repay in full", BankButton::Repay, ui_width);
+                        });
+                });
+        });
+}
+
+fn spawn_heading(commands: &mut ChildBuilder, asset_server: &AssetServer, heading: &str, base_width: f32) {
+    commands.spawn(TextBundle {
+        style: Style {
+            margin: UiRect::top(Val::Percent(4.0)),
+            ..default()
+        },
+        text: Text::from_section(
+            heading,
+            TextStyle {
+                font: asset_server.load("font.otf"),
+                font_size: base_width / 30.0,
+                color: Color::rgb(0.55, 0.44, 0.95),
+            },
+        ),
+        ..default()
+    });
+}
+
+fn spawn_bank_button(
+    commands: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label: &str,
+    bank_button: BankButton,
+    base_width: f32,
+) {
+    commands
+        .spawn(ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Percent(90.0), Val::Percent(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::top(Val::Percent(2.0)),
+                ..default()
+            },
+            background_color: Color::rgb(0.22, 0.25, 0.48).into(),
+            ..default()
+        })
+        .insert(bank_button)
+        .insert(Name::new(format!("{label} button")))
+        .with_children(|commands| {
+            commands.spawn(TextBundle::from_section(
+                label,
+                TextStyle {
+                    font: asset_server.load("font.otf"),
+                    font_size: base_width / 55.0,
+                    color: Color::WHITE,
+                },
+            ));
+        });
+}
+
+fn undraw_bank(mut commands: Commands, ui_root: Query<Entity, With<BankUIRoot>>) {
+    for entity in ui_root.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::complexity)]
+fn update_bank_text(
+    bank: Res<Bank>,
+    bank_data: Res<BankData>,
+    scheduler: Res<Scheduler>,
+    inventory: Res<Inventory>,
+    mut text_set: ParamSet<(Query<&mut Text, With<SavingsText>>, Query<&mut Text, With<LoanText>>)>,
+) {
+    let savings = savings_text(&bank, &inventory, &bank_data);
+    let loan = loan_text(&bank, &bank_data, &scheduler);
+
+    for mut text in text_set.p0().iter_mut() {
+        if text.sections[0].value != savings {
+            text.sections[0].value = savings.clone();
+        }
+    }
+    for mut text in text_set.p1().iter_mut() {
+        if text.sections[0].value != loan {
+            text.sections[0].value = loan.clone();
+        }
+    }
+}
+
+#[allow(clippy::complexity)]
+fn bank_button_interaction(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &BankButton), Changed<Interaction>>,
+    mut bank: ResMut<Bank>,
+    bank_data: Res<BankData>,
+    mut inventory: ResMut<Inventory>,
+) {
+    for (interaction, mut background_colour, bank_button) in interaction_query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => match *bank_button {
+                BankButton::Deposit(amount) => {
+                    let amount = amount.unwrap_or(inventory.balance).min(inventory.balance);
+
+                    inventory.balance -= amount;
+                    bank.savings += amount;
+                }
+                BankButton::WithdrawAll => {
+                    inventory.balance += bank.savings;
+                    bank.savings = 0;
+                }
+                BankButton::Borrow(index) => {
+                    if let Some(offer) = bank_data.loan_offers.get(index) {
+                        bank.take_loan(offer, &bank_data, &mut inventory);
+                    }
+                }
+                BankButton::Repay => bank.repay_loan(&mut inventory),
+            },
+            Interaction::Hovered => *background_colour = Color::rgb(0.34, 0.37, 0.60).into(),
+            _ => *background_colour = Color::rgb(0.22, 0.25, 0.48).into(),
+        }
+    }
+}
@@ -0,0 +1,519 @@
+use bevy::window::PrimaryWindow;
+
+use crate::*;
+
+pub struct CityCentrePlugin;
+
+impl Plugin for CityCentrePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(draw_city_centre.in_schedule(OnEnter(UiState::CityCentreInfo)))
+            .add_system(undraw_city_centre.in_schedule(OnExit(UiState::CityCentreInfo)))
+            .add_systems(
+                (
+                    exit_uistate,
+                    update_countdowns,
+                    upgrade_button_interaction,
+                    bank_button_interaction,
+                    ledger_button_interaction,
+                )
+                    .in_set(OnUpdate(UiState::CityCentreInfo)),
+            );
+    }
+}
+
+fn exit_uistate(
+    keybinds: Res<Keybinds>,
+    keys: Res<Input<KeyCode>>,
+    previous_camera_state: Res<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+) {
+    if !keys.just_pressed(keybinds.exit_uistate) {
+        return;
+    }
+
+    next_ui_state.set(UiState::None);
+    send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+}
+
+// Marker
+#[derive(Component)]
+struct CityCentreUIRoot;
+
+// Marker
+#[derive(Component)]
+struct NextBoostText;
+
+// Marker
+#[derive(Component)]
+struct NextRestockText;
+
+// Marker
+#[derive(Component)]
+struct UpgradeCityCentreButton;
+
+// Marker
+#[derive(Component)]
+struct BankButton;
+
+// Marker
+#[derive(Component)]
+struct LedgerButton;
+
+/// The player's buildings, i.e. everything except the Market and the Construct
+fn is_player_building(building: &Building) -> bool {
+    !matches!(building.building_type, BuildingType::Market | BuildingType::Construct)
+}
+
+fn countdown_text(label: &str, job: Job, scheduler: &Scheduler) -> String {
+    match scheduler.time_remaining(job) {
+        Some(remaining) => format!("{label} in {}s", remaining.as_secs() + 1),
+        None => format!("{label}: -"),
+    }
+}
+
+#[allow(clippy::complexity)]
+fn draw_city_centre(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    buildings: Query<(Entity, &Building)>,
+    upgrade_data: Res<UpgradeData>,
+    scheduler: Res<Scheduler>,
+    achievements: Res<Achievements>,
+) {
+    // UI takes up half the screen & renders at a ratio of 1:1.777
+    let mut ui_width = primary_window.single().resolution.width() / 2.0;
+    let mut ui_height = ui_width / (1920.0 / 1080.0);
+
+    if ui_height > primary_window.single().resolution.height() {
+        ui_height = primary_window.single().resolution.height() / 2.0;
+        ui_width = ui_height * (1920.0 / 1080.0);
+    }
+
+    let city_centre_level = buildings
+        .iter()
+        .find(|(_, building)| building.building_type == BuildingType::CityCentre)
+        .map(|(_, building)| building.level)
+        .unwrap_or_default();
+
+    // Buildings by type and level
+    let mut building_lines = vec![];
+
+    for building_type in [
+        BuildingType::CandyShop,
+        BuildingType::CoffeeShop,
+        BuildingType::Tree,
+        BuildingType::Factory,
+        BuildingType::Cabin,
+    ] {
+        let mut levels = buildings
+            .iter()
+            .filter(|(_, building)| building.building_type == building_type)
+            .map(|(_, building)| building.level)
+            .collect::<Vec<u8>>();
+
+        if levels.is_empty() {
+            continue;
+        }
+
+        levels.sort();
+        levels.dedup();
+
+        let per_level = levels
+            .iter()
+            .map(|level| {
+                let count = buildings
+                    .iter()
+                    .filter(|(_, building)| building.building_type == building_type && building.level == *level)
+                    .count();
+
+                format!("Lv{level} x{count}")
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let total = buildings
+            .iter()
+            .filter(|(_, building)| building.building_type == building_type)
+            .count();
+
+        building_lines.push(format!("{}: {total} ({per_level})", building_type.get_name()));
+    }
+
+    if building_lines.is_empty() {
+        building_lines.push("No buildings yet - visit the Construct".to_string());
+    }
+
+    // Pending yields across every building
+    let mut pending_yields: Vec<(ItemType, u32)> = vec![];
+
+    for (_, building) in buildings.iter() {
+        for (item_type, quantity) in building.yields.iter() {
+            if *quantity == 0 {
+                continue;
+            }
+
+            match pending_yields
+                .iter_mut()
+                .find(|(pending_type, _)| pending_type == item_type)
+            {
+                Some((_, pending_quantity)) => *pending_quantity += quantity,
+                None => pending_yields.push((*item_type, *quantity)),
+            }
+        }
+    }
+
+    let pending_text = if pending_yields.is_empty() {
+        "Nothing to collect".to_string()
+    } else {
+        pending_yields
+            .iter()
+            .map(|(item_type, quantity)| format!("{} x{quantity}", Item::new(*item_type, 0).name))
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    let income_per_minute = buildings
+        .iter()
+        .filter(|(_, building)| is_player_building(building))
+        .map(|(_, building)| upgrade_data.income_per_minute(building.building_type, building.level))
+        .sum::<f32>();
+
+    let counters = &achievements.counters;
+    let lifetime_text = [
+        format!("Buildings constructed: {}", counters.constructions),
+        format!("Upgrades: {}", counters.upgrades),
+        format!("Items collected: {}", counters.items_collected),
+        format!("Items sold: {}", counters.items_sold),
+        format!("Items bought: {}", counters.items_bought),
+        format!("Achievements: {}", achievements.unlocked.len()),
+    ]
+    .join("\n");
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.9).into(),
+            ..default()
+        })
+        .insert(Name::new("City Centre UI Root"))
+        .insert(CityCentreUIRoot)
+        .with_children(|commands| {
+            commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(ui_width), Val::Px(ui_height)),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceEvenly,
+                        align_items: AlignItems::Center,
+                        align_self: AlignSelf::Center,
+                        margin: UiRect::left(Val::Px(
+                            // Offset required for the centre of the UI width to align with centre of screen
+                            (primary_window.single().resolution.width() - ui_width) / 2.0,
+                        )),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.13, 0.14, 0.26).into(),
+                    ..default()
+                })
+                .insert(Name::new("Layout"))
+                .with_children(|commands| {
+                    // Left half
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(45.0), Val::Percent(90.0)),
+                                flex_direction: FlexDirection::Column,
+                                ..default()
+                            },
+                            background_color: Color::rgb(0.17, 0.19, 0.36).into(),
+                            ..default()
+                        })
+                        .insert(Name::new("Left side container"))
+                        .with_children(|commands| {
+                            commands.spawn(TextBundle {
+                                style: Style {
+                                    margin: UiRect::all(Val::Percent(4.0)),
+                                    align_self: AlignSelf::Center,
+                                    ..default()
+                                },
+                                text: Text::from_section(
+                                    format!("City Centre - Level {city_centre_level}"),
+                                    TextStyle {
+                                        font: asset_server.load("font.otf"),
+                                        font_size: ui_width / 30.0,
+                                        color: Color::WHITE,
+                                    },
+                                ),
+                                ..default()
+                            });
+
+                            spawn_info_section(
+                                commands,
+                                &asset_server,
+                                "Buildings",
+                                building_lines.join("\n"),
+                                ui_width,
+                            );
+                            spawn_info_section(commands, &asset_server, "Pending yields", pending_text, ui_width);
+                        });
+
+                    // Right half
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(45.0), Val::Percent(90.0)),
+                                flex_direction: FlexDirection::Column,
+                                ..default()
+                            },
+                            background_color: Color::rgb(0.17, 0.19, 0.36).into(),
+                            ..default()
+                        })
+                        .insert(Name::new("Right side container"))
+                        .with_children(|commands| {
+                            spawn_info_section(
+                                commands,
+                                &asset_server,
+                                "Income (est.)",
+                                format!("${income_per_minute:.0} per minute"),
+                                ui_width,
+                            );
+
+                            commands
+                                .spawn(TextBundle {
+                                    style: Style {
+                                        margin: UiRect::new(
+                                            Val::Percent(4.0),
+                                            Val::Percent(4.0),
+                                            Val::Percent(2.0),
+                                            Val::Percent(0.0),
+                                        ),
+                                        ..default()
+                                    },
+                                    text: Text::from_section(
+                                        countdown_text("Next boost rotation", Job::BoostRotation, &scheduler),
+                                        TextStyle {
+                                            font: asset_server.load("font.otf"),
+                                            font_size: ui_width / 50.0,
+                                            color: Color::WHITE,
+                                        },
+                                    ),
+                                    ..default()
+                                })
+                                .insert(NextBoostText);
+
+                            commands
+                                .spawn(TextBundle {
+                                    style: Style {
+                                        margin: UiRect::new(
+                                            Val::Percent(4.0),
+                                            Val::Percent(4.0),
+                                            Val::Percent(2.0),
+                                            Val::Percent(0.0),
+                                        ),
+                                        ..default()
+                                    },
+                                    text: Text::from_section(
+                                        countdown_text("Next market restock", Job::MarketRestock, &scheduler),
+                                        TextStyle {
+                                            font: asset_server.load("font.otf"),
+                                            font_size: ui_width / 50.0,
+                                            color: Color::WHITE,
+                                        },
+                                    ),
+                                    ..default()
+                                })
+                                .insert(NextRestockText);
+
+                            spawn_info_section(commands, &asset_server, "Lifetime", lifetime_text, ui_width);
+
+                            // Bank & upgrade buttons
+                            commands
+                                .spawn(NodeBundle {
+                                    style: Style {
+                                        size: Size::new(Val::Percent(100.0), Val::Percent(20.0)),
+                                        justify_content: JustifyContent::SpaceEvenly,
+                                        align_items: AlignItems::Center,
+                                        margin: UiRect::top(Val::Auto),
+                                        ..default()
+                                    },
+                                    ..default()
+                                })
+                                .with_children(|commands| {
+                                    spawn_panel_button(commands, &asset_server, "Bank", BankButton, ui_width);
+                                    spawn_panel_button(commands, &asset_server, "Ledger", LedgerButton, ui_width);
+                                    spawn_panel_button(
+                                        commands,
+                                        &asset_server,
+                                        "Upgrade",
+                                        UpgradeCityCentreButton,
+                                        ui_width,
+                                    );
+                                });
+                        });
+                });
+        });
+}
+
+fn spawn_panel_button(
+    commands: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label: &str,
+    marker: impl Component,
+    base_width: f32,
+) {
+    commands
+        .spawn(ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Percent(30.0), Val::Percent(50.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: Color::rgb(0.22, 0.25, 0.48).into(),
+            ..default()
+        })
+        .insert(marker)
+        .insert(Name::new(format!("{label} button")))
+        .with_children(|commands| {
+            commands.spawn(TextBundle::from_section(
+                label,
+                TextStyle {
+                    font: asset_server.load("font.otf"),
+                    font_size: base_width / 30.0,
+                    color: Color::WHITE,
+                },
+            ));
+        });
+}
+
+fn spawn_info_section(
+    commands: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    heading: &str,
+    body: String,
+    base_width: f32,
+) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                margin: UiRect::new(
+                    Val::Percent(4.0),
+                    Val::Percent(4.0),
+                    Val::Percent(2.0),
+                    Val::Percent(2.0),
+                ),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(Name::new(format!("{heading} section")))
+        .with_children(|commands| {
+            commands.spawn(TextBundle::from_section(
+                heading,
+                TextStyle {
+                    font: asset_server.load("font.otf"),
+                    font_size: base_width / 40.0,
+                    color: Color::rgb(0.55, 0.44, 0.95),
+                },
+            ));
+            commands.spawn(TextBundle::from_section(
+                body,
+                TextStyle {
+                    font: asset_server.load("font.otf"),
+                    font_size: base_width / 55.0,
+                    color: Color::WHITE,
+                },
+            ));
+        });
+}
+
+fn undraw_city_centre(mut commands: Commands, ui_root: Query<Entity, With<CityCentreUIRoot>>) {
+    for entity in ui_root.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::complexity)]
+fn update_countdowns(
+    scheduler: Res<Scheduler>,
+    mut text_set: ParamSet<(
+        Query<&mut Text, With<NextBoostText>>,
+        Query<&mut Text, With<NextRestockText>>,
+    )>,
+) {
+    let boost_text = countdown_text("Next boost rotation", Job::BoostRotation, &scheduler);
+    let restock_text = countdown_text("Next market restock", Job::MarketRestock, &scheduler);
+
+    for mut text in text_set.p0().iter_mut() {
+        if text.sections[0].value != boost_text {
+            text.sections[0].value = boost_text.clone();
+        }
+    }
+    for mut text in text_set.p1().iter_mut() {
+        if text.sections[0].value != restock_text {
+            text.sections[0].value = restock_text.clone();
+        }
+    }
+}
+
+#[allow(clippy::complexity)]
+fn upgrade_button_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<UpgradeCityCentreButton>),
+    >,
+    buildings: Query<(Entity, &Building)>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+    mut send_upgrade_target: EventWriter<UpgradeTarget>,
+) {
+    for (interaction, mut background_colour) in interaction_query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => {
+                if let Some((entity, _)) = buildings
+                    .iter()
+                    .find(|(_, building)| building.building_type == BuildingType::CityCentre)
+                {
+                    // The camera stays frozen - leaving the upgrade UI restores the previous camera state
+                    next_ui_state.set(UiState::Upgrade);
+                    send_upgrade_target.send(UpgradeTarget { target_entity: entity });
+                }
+            }
+            Interaction::Hovered => *background_colour = Color::rgb(0.34, 0.37, 0.60).into(),
+            _ => *background_colour = Color::rgb(0.22, 0.25, 0.48).into(),
+        }
+    }
+}
+
+#[allow(clippy::complexity)]
+fn bank_button_interaction(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<BankButton>)>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+) {
+    for (interaction, mut background_colour) in interaction_query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => next_ui_state.set(UiState::Bank),
+            Interaction::Hovered => *background_colour = Color::rgb(0.34, 0.37, 0.60).into(),
+            _ => *background_colour = Color::rgb(0.22, 0.25, 0.48).into(),
+        }
+    }
+}
+
+#[allow(clippy::complexity)]
+fn ledger_button_interaction(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<LedgerButton>)>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+) {
+    for (interaction, mut background_colour) in interaction_query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => next_ui_state.set(UiState::Ledger),
+            Interaction::Hovered => *background_colour = Color::rgb(0.34, 0.37, 0.60).into(),
+            _ => *background_colour = Color::rgb(0.22, 0.25, 0.48).into(),
+        }
+    }
+}
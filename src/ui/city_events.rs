@@ -0,0 +1,187 @@
+use bevy::window::PrimaryWindow;
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::*;
+
+/// Events that are currently running
+#[derive(Resource, Default)]
+pub struct CityEvents {
+    pub active: Vec<ActiveCityEvent>,
+}
+
+pub struct ActiveCityEvent {
+    // Index into CityEventData.events
+    pub index: usize,
+    pub timer: Timer,
+}
+
+pub struct CityEventPlugin;
+
+impl Plugin for CityEventPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Modifiers>()
+            .init_resource::<CityEvents>()
+            .add_startup_systems((schedule_city_events, draw_event_banner))
+            .add_systems((roll_city_events, update_event_banner).chain());
+    }
+}
+
+fn schedule_city_events(mut scheduler: ResMut<Scheduler>, city_event_data: Res<CityEventData>) {
+    scheduler.schedule(Job::CityEventRoll, city_event_data.interval);
+}
+
+fn roll_city_events(
+    game_clock: Res<GameClock>,
+    scheduler: Res<Scheduler>,
+    city_event_data: Res<CityEventData>,
+    mut city_events: ResMut<CityEvents>,
+    mut modifiers: ResMut<Modifiers>,
+    mut market_inventory: ResMut<MarketInventory>,
+) {
+    let mut changed = false;
+
+    for event in city_events.active.iter_mut() {
+        event.timer.tick(game_clock.delta());
+    }
+
+    city_events.active.retain(|event| {
+        if event.timer.finished() {
+            info!("City event over: {}", city_event_data.events[event.index].name);
+            changed = true;
+        }
+
+        !event.timer.finished()
+    });
+
+    for _ in 0..scheduler.times_due(Job::CityEventRoll) {
+        let mut rng = rand::thread_rng();
+
+        let next_event = (0..city_event_data.events.len())
+            .filter(|index| !city_events.active.iter().any(|event| event.index == *index))
+            .choose(&mut rng);
+
+        if let Some(index) = next_event {
+            if rng.gen_range(0..100) < city_event_data.chance {
+                let definition = &city_event_data.events[index];
+                info!("City event: {}", definition.name);
+
+                city_events.active.push(ActiveCityEvent {
+                    index,
+                    timer: Timer::from_seconds(definition.duration, TimerMode::Once),
+                });
+                changed = true;
+            }
+        }
+    }
+
+    if !changed {
+        return;
+    }
+
+    modifiers.set(
+        city_events
+            .active
+            .iter()
+            .flat_map(|event| city_event_data.events[event.index].modifiers.iter().copied())
+            .collect(),
+    );
+
+    // Shortages empty what the market already has - restocks are handled in restock_market
+    for item in market_inventory.items.iter_mut() {
+        if modifiers.is_short(item.item_type) {
+            item.quantity = 0;
+        }
+    }
+}
+
+// Marker
+#[derive(Component)]
+struct EventBannerRoot;
+
+// Marker
+#[derive(Component)]
+struct EventBannerText;
+
+fn draw_event_banner(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let banner_width = primary_window.single().resolution.width() / 3.0;
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(banner_width), Val::Auto),
+                justify_content: JustifyContent::Center,
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Px((primary_window.single().resolution.width() - banner_width) / 2.0),
+                    top: Val::Percent(2.0),
+                    ..default()
+                },
+                padding: UiRect::all(Val::Px(banner_width / 60.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.55, 0.44, 0.95, 0.85).into(),
+            visibility: Visibility::Hidden,
+            // Banners show on top of every other UI
+            z_index: ZIndex::Global(10),
+            ..default()
+        })
+        .insert(Name::new("Event banner"))
+        .insert(EventBannerRoot)
+        .with_children(|commands| {
+            commands
+                .spawn(TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load("font.otf"),
+                        font_size: banner_width / 25.0,
+                        color: Color::WHITE,
+                    },
+                ))
+                .insert(EventBannerText);
+        });
+}
+
+fn update_event_banner(
+    city_events: Res<CityEvents>,
+    city_event_data: Res<CityEventData>,
+    mut banner: Query<&mut Visibility, With<EventBannerRoot>>,
+    mut banner_text: Query<&mut Text, With<EventBannerText>>,
+) {
+    let text = city_events
+        .active
+        .iter()
+        .map(|event| {
+            let definition = &city_event_data.events[event.index];
+
+            format!(
+                "{} - {} ({}s)",
+                definition.name,
+                definition.description,
+                event.timer.remaining().as_secs() + 1
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    for mut visibility in banner.iter_mut() {
+        let target = if text.is_empty() {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+
+        if *visibility != target {
+            *visibility = target;
+        }
+    }
+
+    for mut banner_text in banner_text.iter_mut() {
+        if banner_text.sections[0].value != text {
+            banner_text.sections[0].value = text.clone();
+        }
+    }
+}
@@ -26,6 +26,7 @@ impl BuildingItem {
             Tree => 2000,
             Factory => 3000,
             Cabin => 2500,
+            Flowerbed => 300,
             _ => 0,
         }
     }
@@ -38,6 +39,7 @@ impl BuildingItem {
             Tree => "Tree",
             Factory => "Factory",
             Cabin => "Cabin",
+            Flowerbed => "Flowerbed",
             _ => "Untitled",
         }
         .to_string()
@@ -46,23 +48,34 @@ impl BuildingItem {
 
 #[derive(Resource)]
 pub struct ConstructInventory {
-    pub items: [BuildingItem; 5],
+    pub items: [BuildingItem; 6],
 }
 
 impl Default for ConstructInventory {
+    // Empty until stock_construct fills it on startup
     fn default() -> Self {
         Self {
             items: [
-                BuildingItem::new(BuildingType::CandyShop, 4),
-                BuildingItem::new(BuildingType::CoffeeShop, 3),
-                BuildingItem::new(BuildingType::Tree, 3),
-                BuildingItem::new(BuildingType::Factory, 2),
-                BuildingItem::new(BuildingType::Cabin, 2),
+                BuildingItem::new(BuildingType::CandyShop, 0),
+                BuildingItem::new(BuildingType::CoffeeShop, 0),
+                BuildingItem::new(BuildingType::Tree, 0),
+                BuildingItem::new(BuildingType::Factory, 0),
+                BuildingItem::new(BuildingType::Cabin, 0),
+                // Decorations
+                BuildingItem::new(BuildingType::Flowerbed, 0),
             ],
         }
     }
 }
 
+/// Level of the City Centre, which decides what the Construct sells
+fn city_level(buildings: &Query<&Building>) -> u8 {
+    buildings
+        .iter()
+        .find(|building| building.building_type == BuildingType::CityCentre)
+        .map_or(1, |building| building.level)
+}
+
 pub struct ConstructPlugin;
 
 impl Plugin for ConstructPlugin {
@@ -72,6 +85,9 @@ impl Plugin for ConstructPlugin {
             .init_resource::<SelectedItemStats>()
             .init_resource::<BuildingStash>()
             .add_event::<ChangeItemStatsEvent>()
+            .add_startup_system(schedule_restock)
+            .add_startup_system(stock_construct)
+            .add_system(restock_construct)
             .add_system(draw_construct.in_schedule(OnEnter(UiState::Construct)))
             .add_system(undraw_construct.in_schedule(OnExit(UiState::Construct)))
             .add_system(undraw_construct.in_schedule(OnEnter(ConstructPhase::Preview)))
@@ -83,7 +99,54 @@ impl Plugin for ConstructPlugin {
                     buy_button_interaction,
                 )
                     .in_set(OnUpdate(ConstructPhase::Normal)),
-            );
+            )
+            .add_system(update_stock_text.in_set(OnUpdate(UiState::Construct)))
+            .add_system(cancel_preview.in_set(OnUpdate(ConstructPhase::Preview)));
+    }
+}
+
+fn schedule_restock(mut scheduler: ResMut<Scheduler>, construct_data: Res<ConstructData>) {
+    scheduler.schedule(Job::ConstructRestock, construct_data.restock_period);
+}
+
+/// Restores the saved stock, or gives a new city full stock of whatever a level 1 City Centre unlocks
+fn stock_construct(
+    loaded_save: Res<LoadedSave>,
+    construct_data: Res<ConstructData>,
+    mut construct_inventory: ResMut<ConstructInventory>,
+) {
+    let saved_stock = loaded_save
+        .0
+        .as_ref()
+        .map(|save_file| &save_file.construct_stock)
+        .filter(|stock| !stock.is_empty());
+
+    for item in construct_inventory.items.iter_mut() {
+        item.quantity = match saved_stock {
+            Some(stock) => stock
+                .iter()
+                .find(|(building_type, _)| *building_type == item.building_type)
+                .map_or(0, |(_, quantity)| *quantity),
+            None => construct_data.cap(item.building_type, 1),
+        };
+    }
+}
+
+/// Brings one more of every unlocked building type, up to what the City Centre's level allows
+fn restock_construct(
+    scheduler: Res<Scheduler>,
+    construct_data: Res<ConstructData>,
+    mut construct_inventory: ResMut<ConstructInventory>,
+    buildings: Query<&Building>,
+) {
+    let city_level = city_level(&buildings);
+
+    for _ in 0..scheduler.times_due(Job::ConstructRestock) {
+        for item in construct_inventory.items.iter_mut() {
+            if item.quantity < construct_data.cap(item.building_type, city_level) {
+                item.quantity += 1;
+            }
+        }
     }
 }
 
@@ -92,6 +155,8 @@ pub enum ConstructPhase {
     #[default]
     Normal,
     Preview,
+    /// Painting roads onto tiles
+    Roads,
 }
 
 pub fn exit_uistate(
@@ -110,6 +175,49 @@ pub fn exit_uistate(
     send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
 }
 
+/// Backs out of placing a building - a bought one is refunded, a moved one stays where it was
+#[allow(clippy::complexity)]
+fn cancel_preview(
+    keybinds: Res<Keybinds>,
+    keys: Res<Input<KeyCode>>,
+    mut commands: Commands,
+    mut building_stash: ResMut<BuildingStash>,
+    mut inventory: ResMut<Inventory>,
+    mut construct_inventory: ResMut<ConstructInventory>,
+    preview_ghosts: Query<Entity, With<ConstructPreviewGhost>>,
+    previous_camera_state: Res<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut next_construct_state: ResMut<NextState<ConstructPhase>>,
+) {
+    if !keys.just_pressed(keybinds.exit_uistate) {
+        return;
+    }
+
+    if let Some(entity) = building_stash.relocating.take() {
+        commands.entity(entity).insert(Visibility::Inherited);
+    } else if let Some(building_type) = building_stash.building_type {
+        inventory.balance += building_stash.price;
+
+        if let Some(item) = construct_inventory
+            .items
+            .iter_mut()
+            .find(|item| item.building_type == building_type)
+        {
+            item.quantity += 1;
+        }
+    }
+
+    for entity in preview_ghosts.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    building_stash.building_type = None;
+    next_construct_state.set(ConstructPhase::Normal);
+    send_change_camera_state_event.send(ChangeCameraStateEvent(
+        previous_camera_state.0.clone().unwrap_or(CameraState::CursorLocked),
+    ));
+}
+
 // Marker
 #[derive(Component)]
 struct ConstructUIRoot;
@@ -123,7 +231,9 @@ struct ConstructItemButton {
 }
 
 #[derive(Component)]
-struct MiniQuantityText;
+struct MiniQuantityText {
+    building_type: Option<BuildingType>,
+}
 
 #[derive(Component)]
 struct ItemStatsName;
@@ -313,18 +423,11 @@ fn draw_construct(
                                                     image: UiImage {
                                                         texture: {
                                                             if i < construct_inventory.items.len() {
-                                                                match construct_inventory.items[i].building_type {
-                                                                    BuildingType::CandyShop => {
-                                                                        item_icons.candy_shop.clone()
-                                                                    }
-                                                                    BuildingType::CoffeeShop => {
-                                                                        item_icons.coffee_shop.clone()
-                                                                    }
-                                                                    BuildingType::Tree => item_icons.tree.clone(),
-                                                                    BuildingType::Factory => item_icons.factory.clone(),
-                                                                    BuildingType::Cabin => item_icons.cabin.clone(),
-                                                                    _ => item_icons.empty.clone(),
-                                                                }
+                                                                item_icons
+                                                                    .get_building(
+                                                                        construct_inventory.items[i].building_type,
+                                                                    )
+                                                                    .unwrap_or(item_icons.empty.clone())
                                                             } else {
                                                                 item_icons.empty.clone()
                                                             }
@@ -365,7 +468,12 @@ fn draw_construct(
                                                         ),
                                                         ..default()
                                                     })
-                                                    .insert(MiniQuantityText)
+                                                    .insert(MiniQuantityText {
+                                                        building_type: construct_inventory
+                                                            .items
+                                                            .get(i)
+                                                            .map(|item| item.building_type),
+                                                    })
                                                     .insert(Name::new("Quantity text"));
                                             });
                                     }
@@ -563,8 +671,17 @@ fn undraw_construct(
     }
 }
 
+/// The building that was bought from the Construct and is waiting to be placed
 #[derive(Resource, Default)]
-pub struct BuildingStash(pub Option<BuildingType>);
+pub struct BuildingStash {
+    pub building_type: Option<BuildingType>,
+    // What was paid for it
+    pub price: u32,
+    /// An already placed building that is being moved instead of a new one
+    pub relocating: Option<Entity>,
+    // Quarter turns, see Building.rotation
+    pub rotation: u8,
+}
 
 #[allow(clippy::complexity)]
 fn buy_button_interaction(
@@ -576,11 +693,17 @@ fn buy_button_interaction(
     mut next_construct_state: ResMut<NextState<ConstructPhase>>,
     mut next_ui_state: ResMut<NextState<UiState>>,
     mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    construct_data: Res<ConstructData>,
+    buildings: Query<&Building>,
 ) {
     for (interaction, mut background_colour) in interaction_query.iter_mut() {
         match interaction {
             Interaction::Clicked => {
-                if inventory.balance >= selected_item_stats.buy_price {
+                let unlocked = selected_item_stats
+                    .building_type
+                    .is_some_and(|building_type| construct_data.is_unlocked(building_type, city_level(&buildings)));
+
+                if unlocked && inventory.balance >= selected_item_stats.buy_price {
                     // Buy it
                     let item = construct_inventory
                         .items
@@ -592,7 +715,8 @@ fn buy_button_interaction(
                         item.quantity -= 1;
                         inventory.balance -= item.price;
 
-                        building_stash.0 = Some(item.building_type);
+                        building_stash.building_type = Some(item.building_type);
+                        building_stash.price = item.price;
                         next_construct_state.set(ConstructPhase::Preview);
                         next_ui_state.set(UiState::None);
                         send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::ConstructPreview));
@@ -668,11 +792,9 @@ fn change_item_stats(
     mut image: Query<&mut UiImage, With<ItemStatsImage>>,
     mut param_set: ParamSet<(
         Query<&mut Text, With<ItemStatsName>>,
-        Query<&mut Text, With<ItemStatsQuantity>>,
         Query<&mut Text, With<ItemStatsBuyPrice>>,
     )>,
     mut change_item_stats_events: EventReader<ChangeItemStatsEvent>,
-    construct_inventory: Res<ConstructInventory>,
 ) {
     for event in change_item_stats_events.iter() {
         let mut image = image.single_mut();
@@ -681,15 +803,74 @@ fn change_item_stats(
         for mut name in param_set.p0().iter_mut() {
             name.sections[0].value = event.name.clone();
         }
-        for mut quantity in param_set.p1().iter_mut() {
-            let target_item = construct_inventory.items.iter().find(|item| item.name == event.name);
+        for mut sell_price in param_set.p1().iter_mut() {
+            sell_price.sections[0].value = format!("Price: ${}", event.buy_price);
+        }
+    }
+}
 
-            if let Some(item) = target_item {
-                quantity.sections[0].value = format!("Remaining: {}", item.quantity);
-            }
+/// Keeps quantities live while the shop is open, since stock comes back over time
+#[allow(clippy::complexity)]
+fn update_stock_text(
+    mut param_set: ParamSet<(
+        Query<(&mut Text, &MiniQuantityText)>,
+        Query<&mut Text, With<ItemStatsQuantity>>,
+    )>,
+    construct_inventory: Res<ConstructInventory>,
+    construct_data: Res<ConstructData>,
+    scheduler: Res<Scheduler>,
+    selected_item_stats: Res<SelectedItemStats>,
+    buildings: Query<&Building>,
+) {
+    let city_level = city_level(&buildings);
+    let find_item = |building_type: Option<BuildingType>| {
+        construct_inventory
+            .items
+            .iter()
+            .find(|item| Some(item.building_type) == building_type)
+    };
+
+    for (mut text, mini_quantity_text) in param_set.p0().iter_mut() {
+        let Some(item) = find_item(mini_quantity_text.building_type) else {
+            continue;
+        };
+
+        let value = if construct_data.is_unlocked(item.building_type, city_level) {
+            item.quantity.to_string()
+        } else {
+            format!("Lv{}", construct_data.unlock_level(item.building_type))
+        };
+
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
         }
-        for mut sell_price in param_set.p2().iter_mut() {
-            sell_price.sections[0].value = format!("Price: ${}", event.buy_price);
+    }
+
+    let Some(item) = find_item(selected_item_stats.building_type) else {
+        return;
+    };
+
+    let cap = construct_data.cap(item.building_type, city_level);
+    let value = if !construct_data.is_unlocked(item.building_type, city_level) {
+        format!(
+            "Unlocks at City Centre level {}",
+            construct_data.unlock_level(item.building_type)
+        )
+    } else if item.quantity < cap {
+        format!(
+            "Remaining: {}/{cap} - restocks in {:.0}s",
+            item.quantity,
+            scheduler
+                .time_remaining(Job::ConstructRestock)
+                .map_or(0.0, |remaining| remaining.as_secs_f32().ceil())
+        )
+    } else {
+        format!("Remaining: {}/{cap}", item.quantity)
+    };
+
+    for mut text in param_set.p1().iter_mut() {
+        if text.sections[0].value != value {
+            text.sections[0].value = value.clone();
         }
     }
 }
@@ -53,6 +53,7 @@ impl Plugin for InventoryPlugin {
             .add_systems(
                 (
                     item_button_interaction,
+                    reprice_selected_item,
                     increment_button_interaction,
                     change_item_stats,
                     change_sell_quantity,
@@ -74,7 +75,7 @@ pub fn toggle_inventory(
     mut next_ui_state: ResMut<NextState<UiState>>,
     construct_state: Res<State<ConstructPhase>>,
 ) {
-    if construct_state.0 == ConstructPhase::Preview {
+    if construct_state.0 != ConstructPhase::Normal {
         return;
     }
 
@@ -758,6 +759,9 @@ fn sell_button_interaction(
         Query<&mut Text, With<BalanceText>>,
         Query<(&mut Text, &MiniQuantityText)>,
     )>,
+    achievements: Res<Achievements>,
+    achievement_data: Res<AchievementData>,
+    mut send_game_event: EventWriter<GameEvent>,
 ) {
     for (interaction, mut background_colour) in interaction_query.iter_mut() {
         match interaction {
@@ -767,7 +771,11 @@ fn sell_button_interaction(
                         break 'onclick;
                     }
                     // Inventory: add to balance, subtract from items
-                    inventory.balance += selected_item_stats.sell_price * sell_quantity.quantity;
+                    let revenue = apply_percent_bonus(
+                        selected_item_stats.sell_price * sell_quantity.quantity,
+                        achievements.sell_price_bonus(&achievement_data),
+                    );
+                    inventory.balance += revenue;
                     let balance = inventory.balance;
 
                     let item = inventory
@@ -776,6 +784,13 @@ fn sell_button_interaction(
                         .find(|item| item.item_type == selected_item_stats.item_type.unwrap())
                         .unwrap();
 
+                    send_game_event.send(GameEvent::Sold {
+                        item_type: item.item_type,
+                        quantity: sell_quantity.quantity,
+                        revenue,
+                        boosted: item.boosted,
+                    });
+
                     item.quantity -= sell_quantity.quantity;
                     selected_item_stats.quantity = item.quantity;
 
@@ -834,43 +849,26 @@ fn item_button_interaction(
     item_icons: Res<ItemIcons>,
     mut selected_item_stats: ResMut<SelectedItemStats>,
     mut send_change_item_stats_event: EventWriter<ChangeItemStatsEvent>,
+    modifiers: Res<Modifiers>,
 ) {
     for (interaction, mut background_colour, item_button_cmp) in interaction_query.iter_mut() {
         match interaction {
             Interaction::Clicked => {
                 if let Some(item_type) = item_button_cmp.item_type {
                     let target_item = inventory.items.iter().find(|item| item.item_type == item_type).unwrap();
+                    let sell_price = modifiers.sell_price(item_type, target_item.sell_price);
 
                     *selected_item_stats = SelectedItemStats {
                         item_type: Some(item_type),
                         quantity: target_item.quantity,
-                        sell_price: target_item.sell_price,
+                        sell_price,
                     };
 
                     send_change_item_stats_event.send(ChangeItemStatsEvent {
                         name: target_item.name.clone(),
-                        image: match item_type {
-                            ItemType::BronzeCoin => item_icons.bronze_coin.clone(),
-                            ItemType::SilverCoin => item_icons.silver_coin.clone(),
-                            ItemType::GoldCoin => item_icons.gold_coin.clone(),
-                            ItemType::Taffy => item_icons.taffy.clone(),
-                            ItemType::Nougat => item_icons.nougat.clone(),
-                            ItemType::Marshmallow => item_icons.marshmallow.clone(),
-                            ItemType::Coffee => item_icons.coffee.clone(),
-                            ItemType::Cocoa => item_icons.cocoa.clone(),
-                            ItemType::Milkshake => item_icons.milkshake.clone(),
-                            ItemType::Apple => item_icons.apple.clone(),
-                            ItemType::Branch => item_icons.branch.clone(),
-                            ItemType::Honey => item_icons.honey.clone(),
-                            ItemType::Steel => item_icons.steel.clone(),
-                            ItemType::Chip => item_icons.chip.clone(),
-                            ItemType::Phone => item_icons.phone.clone(),
-                            ItemType::Log => item_icons.log.clone(),
-                            ItemType::Lantern => item_icons.lantern.clone(),
-                            ItemType::Axe => item_icons.axe.clone(),
-                        },
+                        image: item_icons.get_item(item_type),
                         quantity: target_item.quantity,
-                        sell_price: target_item.sell_price,
+                        sell_price,
                     });
                 }
             }
@@ -880,6 +878,36 @@ fn item_button_interaction(
     }
 }
 
+/// City events can start or end with an item selected - the stored price is what gets paid
+fn reprice_selected_item(
+    modifiers: Res<Modifiers>,
+    inventory: Res<Inventory>,
+    item_icons: Res<ItemIcons>,
+    mut selected_item_stats: ResMut<SelectedItemStats>,
+    mut send_change_item_stats_event: EventWriter<ChangeItemStatsEvent>,
+) {
+    if !modifiers.is_changed() {
+        return;
+    }
+
+    let Some(item_type) = selected_item_stats.item_type else {
+        return;
+    };
+    let Some(target_item) = inventory.items.iter().find(|item| item.item_type == item_type) else {
+        return;
+    };
+
+    selected_item_stats.quantity = target_item.quantity;
+    selected_item_stats.sell_price = modifiers.sell_price(item_type, target_item.sell_price);
+
+    send_change_item_stats_event.send(ChangeItemStatsEvent {
+        name: target_item.name.clone(),
+        image: item_icons.get_item(item_type),
+        quantity: selected_item_stats.quantity,
+        sell_price: selected_item_stats.sell_price,
+    });
+}
+
 #[allow(clippy::complexity)]
 fn change_item_stats(
     mut image: Query<&mut UiImage, With<ItemStatsImage>>,
@@ -1,6 +1,7 @@
 use crate::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Reflect, FromReflect, Copy, Clone, Debug, PartialEq)]
+#[derive(Reflect, FromReflect, Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ItemType {
     BronzeCoin,
     SilverCoin,
@@ -51,6 +52,49 @@ pub struct ItemIcons {
     pub tree: Handle<Image>,
     pub factory: Handle<Image>,
     pub cabin: Handle<Image>,
+    pub flowerbed: Handle<Image>,
+}
+
+impl ItemIcons {
+    pub fn get_item(&self, item_type: ItemType) -> Handle<Image> {
+        use ItemType::*;
+
+        match item_type {
+            BronzeCoin => self.bronze_coin.clone(),
+            SilverCoin => self.silver_coin.clone(),
+            GoldCoin => self.gold_coin.clone(),
+            Taffy => self.taffy.clone(),
+            Nougat => self.nougat.clone(),
+            Marshmallow => self.marshmallow.clone(),
+            Coffee => self.coffee.clone(),
+            Cocoa => self.cocoa.clone(),
+            Milkshake => self.milkshake.clone(),
+            Apple => self.apple.clone(),
+            Branch => self.branch.clone(),
+            Honey => self.honey.clone(),
+            Steel => self.steel.clone(),
+            Chip => self.chip.clone(),
+            Phone => self.phone.clone(),
+            Log => self.log.clone(),
+            Lantern => self.lantern.clone(),
+            Axe => self.axe.clone(),
+        }
+    }
+
+    /// Only buildings sold at the Construct have icons
+    pub fn get_building(&self, building_type: BuildingType) -> Option<Handle<Image>> {
+        use BuildingType::*;
+
+        match building_type {
+            CandyShop => Some(self.candy_shop.clone()),
+            CoffeeShop => Some(self.coffee_shop.clone()),
+            Tree => Some(self.tree.clone()),
+            Factory => Some(self.factory.clone()),
+            Cabin => Some(self.cabin.clone()),
+            Flowerbed => Some(self.flowerbed.clone()),
+            _ => None,
+        }
+    }
 }
 
 pub fn load_item_icons(mut commands: Commands, asset_server: Res<AssetServer>) {
@@ -82,6 +126,7 @@ pub fn load_item_icons(mut commands: Commands, asset_server: Res<AssetServer>) {
         tree: asset_server.load("icons/buildings/tree256.png"),
         factory: asset_server.load("icons/buildings/factory256.png"),
         cabin: asset_server.load("icons/buildings/cabin256.png"),
+        flowerbed: asset_server.load("icons/buildings/flowerbed256.png"),
     })
 }
 
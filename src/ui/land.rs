@@ -0,0 +1,275 @@
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+const BASE_PLOT_PRICE: u32 = 500;
+// Every plot bought makes the next one this much more expensive, in percent
+const PLOT_PRICE_INCREASE: u32 = 25;
+
+/// The tiles the player is allowed to build on
+#[derive(Resource, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct Land {
+    pub owned: Vec<(u8, u8)>,
+    pub plots_bought: u32,
+}
+
+impl Default for Land {
+    fn default() -> Self {
+        // A small patch around the City Centre, Market and Construct
+        let mut owned = vec![];
+
+        for i in 5..=8 {
+            for j in 4..=7 {
+                owned.push((i, j));
+            }
+        }
+
+        Self { owned, plots_bought: 0 }
+    }
+}
+
+impl Land {
+    pub fn owns(&self, coords: (u8, u8)) -> bool {
+        self.owned.contains(&coords)
+    }
+
+    /// Only plots that share an edge with owned land are for sale
+    pub fn is_adjacent(&self, (i, j): (u8, u8)) -> bool {
+        self.owned
+            .iter()
+            .any(|(owned_i, owned_j)| i.abs_diff(*owned_i) + j.abs_diff(*owned_j) == 1)
+    }
+
+    /// Compounds once per plot bought, saturating at `u32::MAX` - a price no balance can pay -
+    /// instead of overflowing on a large estate
+    pub fn next_plot_price(&self) -> u32 {
+        (0..self.plots_bought).fold(BASE_PLOT_PRICE, |price, _| {
+            apply_percent_bonus(price, PLOT_PRICE_INCREASE)
+        })
+    }
+
+    /// World space (min, max) corners the camera may move within - the owned area plus a margin
+    pub fn camera_bounds(&self) -> (Vec2, Vec2) {
+        let margin = 2.0 * TILE_SIZE;
+
+        let (min, max) =
+            self.owned
+                .iter()
+                .fold((Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)), |(min, max), (i, j)| {
+                    let position = Vec2::new(*i as f32, *j as f32) * TILE_SIZE;
+
+                    (min.min(position), max.max(position))
+                });
+
+        (min - margin, max + margin)
+    }
+}
+
+/// The locked tile that was clicked on
+#[derive(Resource, Default)]
+pub struct LandTarget(pub Option<Entity>);
+
+pub struct LandPlugin;
+
+impl Plugin for LandPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Land>()
+            .init_resource::<LandTarget>()
+            .add_system(draw_buy_land.in_schedule(OnEnter(UiState::BuyLand)))
+            .add_system(undraw_buy_land.in_schedule(OnExit(UiState::BuyLand)))
+            .add_systems((exit_uistate, buy_land_button_interaction).in_set(OnUpdate(UiState::BuyLand)));
+    }
+}
+
+fn exit_uistate(
+    keybinds: Res<Keybinds>,
+    keys: Res<Input<KeyCode>>,
+    previous_camera_state: Res<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+) {
+    if !keys.just_pressed(keybinds.exit_uistate) {
+        return;
+    }
+
+    next_ui_state.set(UiState::None);
+    send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+}
+
+// Marker
+#[derive(Component)]
+struct BuyLandUIRoot;
+
+// Marker
+#[derive(Component)]
+struct BuyLandButton;
+
+fn draw_buy_land(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    land: Res<Land>,
+    land_target: Res<LandTarget>,
+    tiles: Query<&Tile>,
+    inventory: Res<Inventory>,
+) {
+    let Some(tile) = land_target.0.and_then(|entity| tiles.get(entity).ok()) else {
+        return;
+    };
+
+    let ui_width = primary_window.single().resolution.width() / 4.0;
+    let price = land.next_plot_price();
+    let (i, j) = tile.coords();
+
+    let (message, can_buy) = if !land.is_adjacent((i, j)) {
+        ("Only plots next to your land are for sale".to_string(), false)
+    } else if inventory.balance < price {
+        (format!("You need ${} more", price - inventory.balance), false)
+    } else {
+        ("Expand your city onto this plot".to_string(), true)
+    };
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+            ..default()
+        })
+        .insert(Name::new("Buy Land UI Root"))
+        .insert(BuyLandUIRoot)
+        .with_children(|commands| {
+            commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(ui_width), Val::Auto),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(ui_width / 20.0)),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.13, 0.14, 0.26).into(),
+                    ..default()
+                })
+                .with_children(|commands| {
+                    commands.spawn(TextBundle::from_section(
+                        format!("Plot ({i}, {j}) - ${price}"),
+                        TextStyle {
+                            font: asset_server.load("font.otf"),
+                            font_size: ui_width / 14.0,
+                            color: Color::rgb(0.55, 0.44, 0.95),
+                        },
+                    ));
+
+                    commands.spawn(
+                        TextBundle::from_section(
+                            message,
+                            TextStyle {
+                                font: asset_server.load("font.otf"),
+                                font_size: ui_width / 22.0,
+                                color: Color::WHITE,
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::vertical(Val::Px(ui_width / 20.0)),
+                            ..default()
+                        }),
+                    );
+
+                    if can_buy {
+                        commands
+                            .spawn(ButtonBundle {
+                                style: Style {
+                                    size: Size::new(Val::Percent(60.0), Val::Px(ui_width / 6.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                background_color: Color::rgb(0.22, 0.25, 0.48).into(),
+                                ..default()
+                            })
+                            .insert(BuyLandButton)
+                            .with_children(|commands| {
+                                commands.spawn(TextBundle::from_section(
+                                    "Buy",
+                                    TextStyle {
+                                        font: asset_server.load("font.otf"),
+                                        font_size: ui_width / 14.0,
+                                        color: Color::WHITE,
+                                    },
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+fn undraw_buy_land(
+    mut commands: Commands,
+    ui_root: Query<Entity, With<BuyLandUIRoot>>,
+    mut land_target: ResMut<LandTarget>,
+) {
+    for entity in ui_root.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    land_target.0 = None;
+}
+
+#[allow(clippy::complexity)]
+fn buy_land_button_interaction(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<BuyLandButton>)>,
+    mut commands: Commands,
+    mut land: ResMut<Land>,
+    land_target: Res<LandTarget>,
+    tiles: Query<&Tile>,
+    grid_index: Res<GridIndex>,
+    tile_materials: Res<TileMaterials>,
+    mut inventory: ResMut<Inventory>,
+    previous_camera_state: Res<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+    mut send_game_event: EventWriter<GameEvent>,
+) {
+    for (interaction, mut background_colour) in interaction_query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => {
+                let Some((tile_entity, tile)) = land_target
+                    .0
+                    .and_then(|entity| tiles.get(entity).ok().map(|tile| (entity, tile)))
+                else {
+                    continue;
+                };
+
+                let price = land.next_plot_price();
+
+                if inventory.balance < price || !land.is_adjacent(tile.coords()) {
+                    continue;
+                }
+
+                inventory.balance -= price;
+                land.owned.push(tile.coords());
+                land.plots_bought += 1;
+
+                commands
+                    .entity(tile_entity)
+                    .remove::<LockedTile>()
+                    .insert(tile_materials.get(grid_index.terrain(tile.coords()), false))
+                    .insert(tile_materials.highlight(grid_index.terrain(tile.coords()), false));
+
+                send_game_event.send(GameEvent::LandBought { cost: price });
+
+                next_ui_state.set(UiState::None);
+                send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+            }
+            Interaction::Hovered => *background_colour = Color::rgb(0.34, 0.37, 0.60).into(),
+            _ => *background_colour = Color::rgb(0.22, 0.25, 0.48).into(),
+        }
+    }
+}
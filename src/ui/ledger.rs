@@ -0,0 +1,396 @@
+use std::collections::VecDeque;
+
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+// How many past bills the ledger keeps
+const LEDGER_LENGTH: usize = 12;
+
+/// History of upkeep bills and tax income
+#[derive(Resource, Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct Ledger {
+    pub entries: VecDeque<LedgerEntry>,
+    pub cycles: u32,
+    // Set while the last upkeep bill couldn't be paid
+    pub unpaid: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct LedgerEntry {
+    pub cycle: u32,
+    pub taxes: u32,
+    pub upkeep: u32,
+    pub paid: bool,
+}
+
+impl Ledger {
+    /// Share of normal production buildings currently run at, in percent
+    pub fn production_percent(&self, upkeep_data: &UpkeepData) -> u32 {
+        if self.unpaid {
+            upkeep_data.unpaid_production
+        } else {
+            100
+        }
+    }
+}
+
+pub struct LedgerPlugin;
+
+impl Plugin for LedgerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Ledger>()
+            .add_startup_system(schedule_upkeep)
+            .add_system(charge_upkeep)
+            .add_system(draw_ledger.in_schedule(OnEnter(UiState::Ledger)))
+            .add_system(undraw_ledger.in_schedule(OnExit(UiState::Ledger)))
+            .add_systems((exit_uistate, update_ledger_text).in_set(OnUpdate(UiState::Ledger)));
+    }
+}
+
+fn schedule_upkeep(mut scheduler: ResMut<Scheduler>, upkeep_data: Res<UpkeepData>) {
+    scheduler.schedule(Job::Upkeep, upkeep_data.period);
+}
+
+/// Returns the (upkeep, taxes) for one period across every building. Decorations produce
+/// nothing, so they are neither charged nor taxed - nor are unfinished buildings, which the
+/// query leaves out
+fn bill(buildings: &Query<&Building, Without<UnderConstruction>>, upkeep_data: &UpkeepData) -> (u32, u32) {
+    buildings
+        .iter()
+        .filter(|building| !matches!(building.building_type, BuildingType::Market | BuildingType::Construct))
+        .filter(|building| !building.building_type.is_decoration())
+        .fold((0, 0), |(upkeep, taxes), building| {
+            (
+                upkeep + upkeep_data.upkeep(building.building_type, building.level),
+                taxes + upkeep_data.tax_per_level * building.level as u32,
+            )
+        })
+}
+
+fn charge_upkeep(
+    scheduler: Res<Scheduler>,
+    upkeep_data: Res<UpkeepData>,
+    mut ledger: ResMut<Ledger>,
+    mut inventory: ResMut<Inventory>,
+    buildings: Query<&Building, Without<UnderConstruction>>,
+    setup_state: Res<State<SetupState>>,
+) {
+    // Billing an empty city would skip the saved buildings' first bill
+    if setup_state.0 != SetupState::SpawnBuildingDone {
+        return;
+    }
+
+    for _ in 0..scheduler.times_due(Job::Upkeep) {
+        let (upkeep, taxes) = bill(&buildings, &upkeep_data);
+
+        inventory.balance += taxes;
+
+        let paid = inventory.balance >= upkeep;
+
+        if paid {
+            inventory.balance -= upkeep;
+        } else {
+            warn!("Couldn't pay ${upkeep} upkeep - production is reduced");
+        }
+
+        ledger.cycles += 1;
+        ledger.unpaid = !paid;
+
+        let cycle = ledger.cycles;
+        ledger.entries.push_front(LedgerEntry {
+            cycle,
+            taxes,
+            upkeep,
+            paid,
+        });
+        ledger.entries.truncate(LEDGER_LENGTH);
+    }
+}
+
+fn exit_uistate(
+    keybinds: Res<Keybinds>,
+    keys: Res<Input<KeyCode>>,
+    previous_camera_state: Res<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+) {
+    if !keys.just_pressed(keybinds.exit_uistate) {
+        return;
+    }
+
+    next_ui_state.set(UiState::None);
+    send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+}
+
+// Marker
+#[derive(Component)]
+struct LedgerUIRoot;
+
+// Marker
+#[derive(Component)]
+struct LedgerStatusText;
+
+// Marker
+#[derive(Component)]
+struct LedgerHistoryText;
+
+fn status_text(
+    ledger: &Ledger,
+    buildings: &Query<&Building, Without<UnderConstruction>>,
+    upkeep_data: &UpkeepData,
+    scheduler: &Scheduler,
+) -> String {
+    let (upkeep, taxes) = bill(buildings, upkeep_data);
+    let net = taxes as i64 - upkeep as i64;
+
+    let mut text = format!(
+        "Taxes: +${taxes}\nUpkeep: -${upkeep}\nNet: {}${}\nNext bill in {}s",
+        if net < 0 { "-" } else { "+" },
+        net.abs(),
+        scheduler
+            .time_remaining(Job::Upkeep)
+            .map_or(0, |remaining| remaining.as_secs() + 1)
+    );
+
+    if ledger.unpaid {
+        text.push_str(&format!(
+            "\n\nUpkeep unpaid - production at {}%",
+            ledger.production_percent(upkeep_data)
+        ));
+    }
+
+    text
+}
+
+fn breakdown_text(buildings: &Query<&Building, Without<UnderConstruction>>, upkeep_data: &UpkeepData) -> String {
+    let mut lines = vec![];
+
+    for building_type in [
+        BuildingType::CityCentre,
+        BuildingType::CandyShop,
+        BuildingType::CoffeeShop,
+        BuildingType::Tree,
+        BuildingType::Factory,
+        BuildingType::Cabin,
+    ] {
+        let of_type = buildings
+            .iter()
+            .filter(|building| building.building_type == building_type)
+            .collect::<Vec<&Building>>();
+
+        if of_type.is_empty() {
+            continue;
+        }
+
+        let upkeep = of_type
+            .iter()
+            .map(|building| upkeep_data.upkeep(building_type, building.level))
+            .sum::<u32>();
+
+        lines.push(format!("{} x{}: -${upkeep}", building_type.get_name(), of_type.len()));
+    }
+
+    lines.join("\n")
+}
+
+fn history_text(ledger: &Ledger) -> String {
+    if ledger.entries.is_empty() {
+        return "No bills yet".to_string();
+    }
+
+    ledger
+        .entries
+        .iter()
+        .map(|entry| {
+            format!(
+                "#{}  +${}  -${}{}",
+                entry.cycle,
+                entry.taxes,
+                entry.upkeep,
+                if entry.paid { "" } else { "  UNPAID" }
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[allow(clippy::complexity)]
+fn draw_ledger(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    ledger: Res<Ledger>,
+    buildings: Query<&Building, Without<UnderConstruction>>,
+    upkeep_data: Res<UpkeepData>,
+    scheduler: Res<Scheduler>,
+) {
+    // UI takes up half the screen & renders at a ratio of 1:1.777
+    let mut ui_width = primary_window.single().resolution.width() / 2.0;
+    let mut ui_height = ui_width / (1920.0 / 1080.0);
+
+    if ui_height > primary_window.single().resolution.height() {
+        ui_height = primary_window.single().resolution.height() / 2.0;
+        ui_width = ui_height * (1920.0 / 1080.0);
+    }
+
+    let heading_style = TextStyle {
+        font: asset_server.load("font.otf"),
+        font_size: ui_width / 30.0,
+        color: Color::rgb(0.55, 0.44, 0.95),
+    };
+    let body_style = TextStyle {
+        font: asset_server.load("font.otf"),
+        font_size: ui_width / 50.0,
+        color: Color::WHITE,
+    };
+    let body_margin = UiRect::new(
+        Val::Percent(4.0),
+        Val::Percent(4.0),
+        Val::Percent(2.0),
+        Val::Percent(2.0),
+    );
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.9).into(),
+            ..default()
+        })
+        .insert(Name::new("Ledger UI Root"))
+        .insert(LedgerUIRoot)
+        .with_children(|commands| {
+            commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(ui_width), Val::Px(ui_height)),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceEvenly,
+                        align_items: AlignItems::Center,
+                        align_self: AlignSelf::Center,
+                        margin: UiRect::left(Val::Px(
+                            // Offset required for the centre of the UI width to align with centre of screen
+                            (primary_window.single().resolution.width() - ui_width) / 2.0,
+                        )),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.13, 0.14, 0.26).into(),
+                    ..default()
+                })
+                .insert(Name::new("Layout"))
+                .with_children(|commands| {
+                    // This period's bill
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(45.0), Val::Percent(90.0)),
+                                flex_direction: FlexDirection::Column,
+                                ..default()
+                            },
+                            background_color: Color::rgb(0.17, 0.19, 0.36).into(),
+                            ..default()
+                        })
+                        .insert(Name::new("Bill container"))
+                        .with_children(|commands| {
+                            commands.spawn(TextBundle::from_section("Ledger", heading_style.clone()).with_style(
+                                Style {
+                                    margin: body_margin,
+                                    ..default()
+                                },
+                            ));
+
+                            commands
+                                .spawn(
+                                    TextBundle::from_section(
+                                        status_text(&ledger, &buildings, &upkeep_data, &scheduler),
+                                        body_style.clone(),
+                                    )
+                                    .with_style(Style {
+                                        margin: body_margin,
+                                        ..default()
+                                    }),
+                                )
+                                .insert(LedgerStatusText);
+
+                            commands.spawn(
+                                TextBundle::from_section(breakdown_text(&buildings, &upkeep_data), body_style.clone())
+                                    .with_style(Style {
+                                        margin: body_margin,
+                                        ..default()
+                                    }),
+                            );
+                        });
+
+                    // Past bills
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(45.0), Val::Percent(90.0)),
+                                flex_direction: FlexDirection::Column,
+                                ..default()
+                            },
+                            background_color: Color::rgb(0.17, 0.19, 0.36).into(),
+                            ..default()
+                        })
+                        .insert(Name::new("History container"))
+                        .with_children(|commands| {
+                            commands.spawn(TextBundle::from_section("History", heading_style.clone()).with_style(
+                                Style {
+                                    margin: body_margin,
+                                    ..default()
+                                },
+                            ));
+
+                            commands
+                                .spawn(
+                                    TextBundle::from_section(history_text(&ledger), body_style.clone()).with_style(
+                                        Style {
+                                            margin: body_margin,
+                                            ..default()
+                                        },
+                                    ),
+                                )
+                                .insert(LedgerHistoryText);
+                        });
+                });
+        });
+}
+
+fn undraw_ledger(mut commands: Commands, ui_root: Query<Entity, With<LedgerUIRoot>>) {
+    for entity in ui_root.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::complexity)]
+fn update_ledger_text(
+    ledger: Res<Ledger>,
+    buildings: Query<&Building, Without<UnderConstruction>>,
+    upkeep_data: Res<UpkeepData>,
+    scheduler: Res<Scheduler>,
+    mut text_set: ParamSet<(
+        Query<&mut Text, With<LedgerStatusText>>,
+        Query<&mut Text, With<LedgerHistoryText>>,
+    )>,
+) {
+    let status = status_text(&ledger, &buildings, &upkeep_data, &scheduler);
+
+    for mut text in text_set.p0().iter_mut() {
+        if text.sections[0].value != status {
+            text.sections[0].value = status.clone();
+        }
+    }
+
+    if ledger.is_changed() {
+        let history = history_text(&ledger);
+
+        for mut text in text_set.p1().iter_mut() {
+            text.sections[0].value = history.clone();
+        }
+    }
+}
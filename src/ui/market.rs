@@ -48,6 +48,7 @@ impl Plugin for MarketPlugin {
                 (
                     exit_uistate,
                     item_button_interaction,
+                    reprice_selected_item,
                     increment_button_interaction,
                     change_item_stats,
                     change_buy_quantity,
@@ -690,6 +691,7 @@ fn buy_button_interaction(
         Query<&mut Text, With<BalanceText>>,
         Query<(&mut Text, &MiniQuantityText)>,
     )>,
+    mut send_game_event: EventWriter<GameEvent>,
 ) {
     for (interaction, mut background_colour) in interaction_query.iter_mut() {
         match interaction {
@@ -699,9 +701,16 @@ fn buy_button_interaction(
                         break 'onclick;
                     }
                     // Inventory: add to balance, subtract from items
-                    inventory.balance -= selected_item_stats.buy_price * buy_quantity.quantity;
+                    let cost = selected_item_stats.buy_price * buy_quantity.quantity;
+                    inventory.balance -= cost;
                     let balance = inventory.balance;
 
+                    send_game_event.send(GameEvent::Bought {
+                        item_type: selected_item_stats.item_type.unwrap(),
+                        quantity: buy_quantity.quantity,
+                        cost,
+                    });
+
                     let item = inventory
                         .items
                         .iter_mut()
@@ -774,6 +783,7 @@ fn item_button_interaction(
     item_icons: Res<ItemIcons>,
     mut selected_item_stats: ResMut<SelectedItemStats>,
     mut send_change_item_stats_event: EventWriter<ChangeItemStatsEvent>,
+    modifiers: Res<Modifiers>,
 ) {
     for (interaction, mut background_colour, item_button_cmp) in interaction_query.iter_mut() {
         match interaction {
@@ -784,37 +794,19 @@ fn item_button_interaction(
                         .iter()
                         .find(|item| item.item_type == item_type)
                         .unwrap();
+                    let buy_price = modifiers.buy_price(item_type, target_item.base_buy_price);
 
                     *selected_item_stats = SelectedItemStats {
                         item_type: Some(item_type),
                         quantity: target_item.quantity,
-                        buy_price: target_item.base_buy_price,
+                        buy_price,
                     };
 
                     send_change_item_stats_event.send(ChangeItemStatsEvent {
                         name: target_item.name.clone(),
-                        image: match item_type {
-                            ItemType::BronzeCoin => item_icons.bronze_coin.clone(),
-                            ItemType::SilverCoin => item_icons.silver_coin.clone(),
-                            ItemType::GoldCoin => item_icons.gold_coin.clone(),
-                            ItemType::Taffy => item_icons.taffy.clone(),
-                            ItemType::Nougat => item_icons.nougat.clone(),
-                            ItemType::Marshmallow => item_icons.marshmallow.clone(),
-                            ItemType::Coffee => item_icons.coffee.clone(),
-                            ItemType::Cocoa => item_icons.cocoa.clone(),
-                            ItemType::Milkshake => item_icons.milkshake.clone(),
-                            ItemType::Apple => item_icons.apple.clone(),
-                            ItemType::Branch => item_icons.branch.clone(),
-                            ItemType::Honey => item_icons.honey.clone(),
-                            ItemType::Steel => item_icons.steel.clone(),
-                            ItemType::Chip => item_icons.chip.clone(),
-                            ItemType::Phone => item_icons.phone.clone(),
-                            ItemType::Log => item_icons.log.clone(),
-                            ItemType::Lantern => item_icons.lantern.clone(),
-                            ItemType::Axe => item_icons.axe.clone(),
-                        },
+                        image: item_icons.get_item(item_type),
                         quantity: target_item.quantity,
-                        buy_price: target_item.base_buy_price,
+                        buy_price,
                     });
                 }
             }
@@ -824,6 +816,36 @@ fn item_button_interaction(
     }
 }
 
+/// City events can start or end with an item selected - the stored price is what gets charged
+fn reprice_selected_item(
+    modifiers: Res<Modifiers>,
+    market_inventory: Res<MarketInventory>,
+    item_icons: Res<ItemIcons>,
+    mut selected_item_stats: ResMut<SelectedItemStats>,
+    mut send_change_item_stats_event: EventWriter<ChangeItemStatsEvent>,
+) {
+    if !modifiers.is_changed() {
+        return;
+    }
+
+    let Some(item_type) = selected_item_stats.item_type else {
+        return;
+    };
+    let Some(target_item) = market_inventory.items.iter().find(|item| item.item_type == item_type) else {
+        return;
+    };
+
+    selected_item_stats.quantity = target_item.quantity;
+    selected_item_stats.buy_price = modifiers.buy_price(item_type, target_item.base_buy_price);
+
+    send_change_item_stats_event.send(ChangeItemStatsEvent {
+        name: target_item.name.clone(),
+        image: item_icons.get_item(item_type),
+        quantity: selected_item_stats.quantity,
+        buy_price: selected_item_stats.buy_price,
+    });
+}
+
 #[allow(clippy::complexity)]
 fn change_item_stats(
     mut image: Query<&mut UiImage, With<ItemStatsImage>>,
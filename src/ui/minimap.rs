@@ -0,0 +1,283 @@
+use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
+
+use crate::*;
+
+// Highlights for buildings that need attention
+const FULL_STORAGE_COLOUR: Color = Color::rgb(0.95, 0.75, 0.3);
+const UPGRADE_READY_COLOUR: Color = Color::rgb(0.35, 0.8, 0.45);
+
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(draw_minimap).add_systems((
+            update_minimap_tiles,
+            update_minimap_camera,
+            minimap_tile_interaction,
+        ));
+    }
+}
+
+// Marker
+#[derive(Component)]
+struct MinimapRoot;
+
+#[derive(Component)]
+struct MinimapTile {
+    coords: (u8, u8),
+}
+
+#[derive(Component)]
+struct MinimapIcon {
+    coords: (u8, u8),
+}
+
+// Marker
+#[derive(Component)]
+struct MinimapCamera;
+
+// Marker - dot in front of the camera marker, showing which way it faces
+#[derive(Component)]
+struct MinimapCameraFacing;
+
+/// Where a world position falls on the minimap, in percent from the top left
+fn minimap_position(x: f32, z: f32) -> (f32, f32) {
+    let map_size = GRID_SIZE as f32 * TILE_SIZE;
+
+    (
+        ((x + TILE_SIZE / 2.0) / map_size * 100.0).clamp(0.0, 100.0),
+        ((z + TILE_SIZE / 2.0) / map_size * 100.0).clamp(0.0, 100.0),
+    )
+}
+
+fn draw_minimap(mut commands: Commands, primary_window: Query<&Window, With<PrimaryWindow>>) {
+    let minimap_size = primary_window.single().resolution.height() / 4.0;
+    let tile_percent = 100.0 / GRID_SIZE as f32;
+    let marker_size = minimap_size / 25.0;
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(minimap_size), Val::Px(minimap_size)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    right: Val::Percent(1.0),
+                    top: Val::Percent(2.0),
+                    ..default()
+                },
+                ..default()
+            },
+            background_color: Color::rgba(0.13, 0.14, 0.26, 0.8).into(),
+            ..default()
+        })
+        .insert(Name::new("Minimap"))
+        .insert(MinimapRoot)
+        .with_children(|commands| {
+            for i in 0..GRID_SIZE {
+                for j in 0..GRID_SIZE {
+                    commands
+                        .spawn(ButtonBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(tile_percent), Val::Percent(tile_percent)),
+                                position_type: PositionType::Absolute,
+                                position: UiRect {
+                                    left: Val::Percent(i as f32 * tile_percent),
+                                    top: Val::Percent(j as f32 * tile_percent),
+                                    ..default()
+                                },
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: Color::NONE.into(),
+                            ..default()
+                        })
+                        .insert(MinimapTile { coords: (i, j) })
+                        .with_children(|commands| {
+                            commands
+                                .spawn(ImageBundle {
+                                    style: Style {
+                                        size: Size::new(Val::Percent(85.0), Val::Percent(85.0)),
+                                        ..default()
+                                    },
+                                    visibility: Visibility::Hidden,
+                                    ..default()
+                                })
+                                .insert(MinimapIcon { coords: (i, j) });
+                        });
+                }
+            }
+
+            // Negative margins centre the markers on their position
+            for (size, marker) in [(marker_size, true), (marker_size / 2.0, false)] {
+                let mut marker_commands = commands.spawn(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(size), Val::Px(size)),
+                        position_type: PositionType::Absolute,
+                        margin: UiRect::new(Val::Px(-size / 2.0), Val::Px(0.0), Val::Px(-size / 2.0), Val::Px(0.0)),
+                        ..default()
+                    },
+                    background_color: if marker {
+                        Color::rgb(0.55, 0.44, 0.95).into()
+                    } else {
+                        Color::WHITE.into()
+                    },
+                    ..default()
+                });
+
+                if marker {
+                    marker_commands.insert(MinimapCamera);
+                } else {
+                    marker_commands.insert(MinimapCameraFacing);
+                }
+            }
+        });
+}
+
+/// Colours tiles by terrain, ownership and roads, and marks buildings that need attention
+#[allow(clippy::complexity)]
+fn update_minimap_tiles(
+    mut minimap_tiles: Query<(&MinimapTile, &mut BackgroundColor)>,
+    mut minimap_icons: Query<(&MinimapIcon, &mut UiImage, &mut Visibility)>,
+    buildings: Query<(Entity, &Building, &Parent, Option<&UnderConstruction>)>,
+    tiles: Query<&Tile>,
+    grid_index: Res<GridIndex>,
+    land: Res<Land>,
+    roads: Res<Roads>,
+    inventory: Res<Inventory>,
+    upgrade_data: Res<UpgradeData>,
+    item_icons: Res<ItemIcons>,
+) {
+    let mut icons = HashMap::new();
+    let mut highlights = HashMap::new();
+
+    for (entity, building, parent, under_construction) in buildings.iter() {
+        if let (Ok(tile), Some(icon)) = (tiles.get(parent.get()), item_icons.get_building(building.building_type)) {
+            icons.insert(tile.coords(), icon);
+        }
+
+        if under_construction.is_some() {
+            continue;
+        }
+
+        if upgrade_data.storage_full(building) {
+            highlights.insert(entity, FULL_STORAGE_COLOUR);
+        } else if upgrade_data.can_afford_upgrade(building.building_type, building.level, &inventory) {
+            highlights.insert(entity, UPGRADE_READY_COLOUR);
+        }
+    }
+
+    for (minimap_tile, mut background_colour) in minimap_tiles.iter_mut() {
+        let coords = minimap_tile.coords;
+        let highlight = grid_index
+            .occupant(coords)
+            .and_then(|occupant| highlights.get(&occupant).copied());
+
+        let colour = match highlight {
+            Some(colour) => colour,
+            None if roads.tiles.contains(&coords) => Color::rgb(0.32, 0.32, 0.35),
+            None if !land.owns(coords) => grid_index.terrain(coords).get_colour() * Vec3::new(0.35, 0.35, 0.4),
+            None => grid_index.terrain(coords).get_colour() * Vec3::new(0.6, 0.6, 0.6),
+        };
+
+        if background_colour.0 != colour {
+            background_colour.0 = colour;
+        }
+    }
+
+    for (minimap_icon, mut image, mut visibility) in minimap_icons.iter_mut() {
+        let target = match icons.get(&minimap_icon.coords) {
+            Some(icon) => {
+                if image.texture != *icon {
+                    image.texture = icon.clone();
+                }
+
+                Visibility::Inherited
+            }
+            None => Visibility::Hidden,
+        };
+
+        if *visibility != target {
+            *visibility = target;
+        }
+    }
+}
+
+#[allow(clippy::complexity)]
+fn update_minimap_camera(
+    camera: Query<&Transform, With<GameCamera>>,
+    mut markers: ParamSet<(
+        Query<&mut Style, With<MinimapCamera>>,
+        Query<&mut Style, With<MinimapCameraFacing>>,
+    )>,
+) {
+    let Ok(camera_transform) = camera.get_single() else {
+        return;
+    };
+
+    let forward = camera_transform.forward();
+    let facing = Vec2::new(forward.x, forward.z).normalize_or_zero() * TILE_SIZE;
+    let translation = camera_transform.translation;
+
+    let place = |style: &mut Style, (x, z): (f32, f32)| {
+        let (left, top) = minimap_position(x, z);
+        let position = UiRect {
+            left: Val::Percent(left),
+            top: Val::Percent(top),
+            ..default()
+        };
+
+        if style.position != position {
+            style.position = position;
+        }
+    };
+
+    for mut style in markers.p0().iter_mut() {
+        place(&mut style, (translation.x, translation.z));
+    }
+
+    for mut style in markers.p1().iter_mut() {
+        place(&mut style, (translation.x + facing.x, translation.z + facing.y));
+    }
+}
+
+/// Moves the camera so it looks at the clicked tile from the same angle and height
+fn minimap_tile_interaction(
+    interaction_query: Query<(&Interaction, &MinimapTile), Changed<Interaction>>,
+    mut camera: Query<&mut Transform, With<GameCamera>>,
+    ui_state: Res<State<UiState>>,
+    land: Res<Land>,
+) {
+    // Full screen menus don't block clicks from reaching the minimap underneath
+    if ui_state.0 != UiState::None {
+        return;
+    }
+
+    for (interaction, minimap_tile) in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+
+        let (i, j) = minimap_tile.coords;
+        let target = Vec2::new(i as f32, j as f32) * TILE_SIZE;
+        let (min, max) = land.camera_bounds();
+
+        for mut camera_transform in camera.iter_mut() {
+            let forward = camera_transform.forward();
+
+            // Distance along the view to the ground, or a fixed distance back when looking at the horizon
+            let offset = if forward.y < -0.1 {
+                Vec2::new(forward.x, forward.z) * camera_transform.translation.y / -forward.y
+            } else {
+                Vec2::new(forward.x, forward.z).normalize_or_zero() * 2.0 * TILE_SIZE
+            };
+
+            // The camera has to stay strictly inside its bounds, or it can't move at all
+            let position = (target - offset).clamp(min + Vec2::splat(0.1), max - Vec2::splat(0.1));
+
+            camera_transform.translation.x = position.x;
+            camera_transform.translation.z = position.y;
+        }
+    }
+}
@@ -1,22 +1,45 @@
+mod achievements;
+mod bank;
+mod city_centre;
+mod city_events;
 mod construct;
 mod inventory;
 mod items;
+mod land;
+mod ledger;
 mod market;
+mod minimap;
+mod quests;
+mod statistics;
 mod upgrade;
 pub use crate::*;
+pub use achievements::*;
+pub use bank::*;
+pub use city_centre::*;
+pub use city_events::*;
 pub use construct::*;
 pub use inventory::*;
 pub use items::*;
+pub use land::*;
+pub use ledger::*;
 pub use market::*;
+pub use minimap::*;
+pub use quests::*;
+pub use statistics::*;
 pub use upgrade::*;
 
 #[derive(States, PartialEq, Eq, Debug, Clone, Hash, Default, Reflect)]
 pub enum UiState {
     Inventory,
-    // CityCentreInfo,
+    CityCentreInfo,
+    Bank,
+    Ledger,
+    BuyLand,
     Market,
     Construct,
     Upgrade,
+    Achievements,
+    Statistics,
     #[default]
     None,
 }
@@ -0,0 +1,235 @@
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// Where the player is in the quest chain
+#[derive(Resource, Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct Quests {
+    // Index into QuestData.quests - equal to its length once every quest is done
+    pub current: usize,
+    pub progress: u32,
+}
+
+pub struct QuestPlugin;
+
+impl Plugin for QuestPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Quests>()
+            .add_system(progress_quests)
+            .add_system(draw_quest_tracker.in_schedule(OnEnter(UiState::None)))
+            .add_system(undraw_quest_tracker.in_schedule(OnExit(UiState::None)))
+            .add_system(update_quest_tracker.in_set(OnUpdate(UiState::None)));
+    }
+}
+
+fn progress_quests(
+    mut game_events: EventReader<GameEvent>,
+    mut quests: ResMut<Quests>,
+    quest_data: Res<QuestData>,
+    mut inventory: ResMut<Inventory>,
+) {
+    for event in game_events.iter() {
+        let Some(quest) = quest_data.quests.get(quests.current) else {
+            break;
+        };
+
+        let progress = quest.goal.progress_from(event);
+
+        if progress > 0 {
+            quests.progress += progress;
+        }
+
+        if quests.progress >= quest.goal.target() {
+            complete_quest(&mut quests, quest, &mut inventory);
+        }
+    }
+
+    // Balance goals aren't driven by events
+    if let Some(
+        quest @ QuestDefinition {
+            goal: QuestGoal::Balance(target),
+            ..
+        },
+    ) = quest_data.quests.get(quests.current)
+    {
+        let progress = inventory.balance.min(*target);
+
+        if quests.progress != progress {
+            quests.progress = progress;
+        }
+
+        if quests.progress >= *target {
+            complete_quest(&mut quests, quest, &mut inventory);
+        }
+    }
+}
+
+/// Hands out the rewards for the current quest and moves on to the next one
+fn complete_quest(quests: &mut Quests, quest: &QuestDefinition, inventory: &mut Inventory) {
+    for reward in quest.rewards.iter() {
+        match *reward {
+            QuestReward::Cash(amount) => inventory.balance += amount,
+            QuestReward::Item(item_type, quantity) => {
+                inventory
+                    .items
+                    .iter_mut()
+                    .find(|item| item.item_type == item_type)
+                    .unwrap()
+                    .quantity += quantity;
+            }
+        }
+    }
+
+    info!("Quest complete: {}", quest.title);
+
+    quests.current += 1;
+    quests.progress = 0;
+}
+
+fn format_rewards(rewards: &[QuestReward]) -> String {
+    rewards
+        .iter()
+        .map(|reward| match *reward {
+            QuestReward::Cash(amount) => format!("${amount}"),
+            QuestReward::Item(item_type, quantity) => format!("{} x{quantity}", Item::new(item_type, 0).name),
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+// Marker
+#[derive(Component)]
+struct QuestTrackerRoot;
+
+// Marker
+#[derive(Component)]
+struct QuestTitleText;
+
+// Marker
+#[derive(Component)]
+struct QuestDescriptionText;
+
+// Marker
+#[derive(Component)]
+struct QuestProgressText;
+
+fn draw_quest_tracker(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    quests: Res<Quests>,
+    quest_data: Res<QuestData>,
+) {
+    let tracker_width = primary_window.single().resolution.width() / 5.0;
+    let (title, description, progress) = tracker_text(&quests, &quest_data);
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(tracker_width), Val::Auto),
+                flex_direction: FlexDirection::Column,
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Percent(1.0),
+                    top: Val::Percent(2.0),
+                    ..default()
+                },
+                padding: UiRect::all(Val::Px(tracker_width / 40.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.13, 0.14, 0.26, 0.8).into(),
+            ..default()
+        })
+        .insert(Name::new("Quest tracker"))
+        .insert(QuestTrackerRoot)
+        .with_children(|commands| {
+            commands
+                .spawn(TextBundle::from_section(
+                    title,
+                    TextStyle {
+                        font: asset_server.load("font.otf"),
+                        font_size: tracker_width / 14.0,
+                        color: Color::rgb(0.55, 0.44, 0.95),
+                    },
+                ))
+                .insert(QuestTitleText);
+
+            commands
+                .spawn(TextBundle::from_section(
+                    description,
+                    TextStyle {
+                        font: asset_server.load("font.otf"),
+                        font_size: tracker_width / 20.0,
+                        color: Color::WHITE,
+                    },
+                ))
+                .insert(QuestDescriptionText);
+
+            commands
+                .spawn(TextBundle::from_section(
+                    progress,
+                    TextStyle {
+                        font: asset_server.load("font.otf"),
+                        font_size: tracker_width / 20.0,
+                        color: Color::GRAY,
+                    },
+                ))
+                .insert(QuestProgressText);
+        });
+}
+
+fn undraw_quest_tracker(mut commands: Commands, tracker_root: Query<Entity, With<QuestTrackerRoot>>) {
+    for entity in tracker_root.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::complexity)]
+fn update_quest_tracker(
+    quests: Res<Quests>,
+    quest_data: Res<QuestData>,
+    mut text_set: ParamSet<(
+        Query<&mut Text, With<QuestTitleText>>,
+        Query<&mut Text, With<QuestDescriptionText>>,
+        Query<&mut Text, With<QuestProgressText>>,
+    )>,
+) {
+    if !quests.is_changed() {
+        return;
+    }
+
+    let (title, description, progress) = tracker_text(&quests, &quest_data);
+
+    for mut text in text_set.p0().iter_mut() {
+        text.sections[0].value = title.clone();
+    }
+    for mut text in text_set.p1().iter_mut() {
+        text.sections[0].value = description.clone();
+    }
+    for mut text in text_set.p2().iter_mut() {
+        text.sections[0].value = progress.clone();
+    }
+}
+
+/// Returns the (title, description, progress) lines shown in the tracker
+fn tracker_text(quests: &Quests, quest_data: &QuestData) -> (String, String, String) {
+    match quest_data.quests.get(quests.current) {
+        Some(quest) => (
+            quest.title.to_string(),
+            quest.description.to_string(),
+            format!(
+                "{}/{} - Reward: {}",
+                quests.progress.min(quest.goal.target()),
+                quest.goal.target(),
+                format_rewards(&quest.rewards)
+            ),
+        ),
+        None => (
+            "All quests complete".to_string(),
+            "Your city is thriving!".to_string(),
+            String::new(),
+        ),
+    }
+}
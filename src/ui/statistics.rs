@@ -0,0 +1,640 @@
+use std::cmp::Ordering;
+
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// Lifetime totals of everything that moved through the city's economy
+#[derive(Resource, Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct Statistics {
+    pub produced_by_type: Vec<(BuildingType, u32)>,
+    pub produced_by_building: Vec<BuildingStatistics>,
+    pub items: Vec<ItemStatistics>,
+    pub spent_on_upgrades: u32,
+    pub spent_on_construction: u32,
+    pub spent_on_land: u32,
+    pub demolition_refunds: u32,
+    // Sales made while the item was boosted
+    pub boosts_used: u32,
+    // Seconds
+    pub play_time: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BuildingStatistics {
+    pub building_type: BuildingType,
+    // Position of the parent tile
+    pub x: f32,
+    pub z: f32,
+    pub produced: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ItemStatistics {
+    pub item_type: ItemType,
+    pub produced: u32,
+    pub sold: u32,
+    pub revenue: u32,
+    pub bought: u32,
+    pub cost: u32,
+}
+
+impl Statistics {
+    fn item_mut(&mut self, item_type: ItemType) -> &mut ItemStatistics {
+        match self.items.iter().position(|item| item.item_type == item_type) {
+            Some(index) => &mut self.items[index],
+            None => {
+                self.items.push(ItemStatistics {
+                    item_type,
+                    produced: 0,
+                    sold: 0,
+                    revenue: 0,
+                    bought: 0,
+                    cost: 0,
+                });
+                self.items.last_mut().unwrap()
+            }
+        }
+    }
+
+    fn record_production(&mut self, building_type: BuildingType, tile: Option<&Tile>, items: &[(ItemType, u32)]) {
+        let total = items.iter().map(|(_, quantity)| quantity).sum::<u32>();
+
+        for (item_type, quantity) in items.iter() {
+            self.item_mut(*item_type).produced += quantity;
+        }
+
+        match self
+            .produced_by_type
+            .iter_mut()
+            .find(|(produced_type, _)| *produced_type == building_type)
+        {
+            Some((_, produced)) => *produced += total,
+            None => self.produced_by_type.push((building_type, total)),
+        }
+
+        let Some((x, z)) = tile.map(Tile::position) else {
+            return;
+        };
+
+        match self
+            .produced_by_building
+            .iter_mut()
+            .find(|building| building.building_type == building_type && building.x == x && building.z == z)
+        {
+            Some(building) => building.produced += total,
+            None => self.produced_by_building.push(BuildingStatistics {
+                building_type,
+                x,
+                z,
+                produced: total,
+            }),
+        }
+    }
+
+    /// Per-building totals are keyed by tile, so they have to follow a relocated building
+    pub fn move_building(&mut self, building_type: BuildingType, from: &Tile, to: &Tile) {
+        let (from_x, from_z) = from.position();
+
+        if let Some(building) = self
+            .produced_by_building
+            .iter_mut()
+            .find(|building| building.building_type == building_type && building.x == from_x && building.z == from_z)
+        {
+            (building.x, building.z) = to.position();
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StatsColumn {
+    Item,
+    Produced,
+    Sold,
+    Revenue,
+    Bought,
+    Cost,
+}
+
+impl StatsColumn {
+    const ALL: [StatsColumn; 6] = [
+        StatsColumn::Item,
+        StatsColumn::Produced,
+        StatsColumn::Sold,
+        StatsColumn::Revenue,
+        StatsColumn::Bought,
+        StatsColumn::Cost,
+    ];
+
+    fn title(&self) -> &'static str {
+        match self {
+            StatsColumn::Item => "Item",
+            StatsColumn::Produced => "Produced",
+            StatsColumn::Sold => "Sold",
+            StatsColumn::Revenue => "Revenue",
+            StatsColumn::Bought => "Bought",
+            StatsColumn::Cost => "Cost",
+        }
+    }
+
+    fn cell(&self, item: &ItemStatistics) -> String {
+        match self {
+            StatsColumn::Item => Item::new(item.item_type, 0).name,
+            StatsColumn::Produced => item.produced.to_string(),
+            StatsColumn::Sold => item.sold.to_string(),
+            StatsColumn::Revenue => format!("${}", item.revenue),
+            StatsColumn::Bought => item.bought.to_string(),
+            StatsColumn::Cost => format!("${}", item.cost),
+        }
+    }
+
+    fn compare(&self, a: &ItemStatistics, b: &ItemStatistics) -> Ordering {
+        match self {
+            StatsColumn::Item => Item::new(a.item_type, 0).name.cmp(&Item::new(b.item_type, 0).name),
+            StatsColumn::Produced => a.produced.cmp(&b.produced),
+            StatsColumn::Sold => a.sold.cmp(&b.sold),
+            StatsColumn::Revenue => a.revenue.cmp(&b.revenue),
+            StatsColumn::Bought => a.bought.cmp(&b.bought),
+            StatsColumn::Cost => a.cost.cmp(&b.cost),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct StatsSort {
+    column: StatsColumn,
+    descending: bool,
+}
+
+impl Default for StatsSort {
+    fn default() -> Self {
+        Self {
+            column: StatsColumn::Revenue,
+            descending: true,
+        }
+    }
+}
+
+pub struct StatisticsPlugin;
+
+impl Plugin for StatisticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Statistics>()
+            .init_resource::<StatsSort>()
+            .add_system(record_statistics)
+            .add_system(toggle_statistics)
+            .add_system(draw_statistics.in_schedule(OnEnter(UiState::Statistics)))
+            .add_system(undraw_statistics.in_schedule(OnExit(UiState::Statistics)))
+            .add_systems(
+                (sort_button_interaction, redraw_table)
+                    .chain()
+                    .in_set(OnUpdate(UiState::Statistics)),
+            );
+    }
+}
+
+fn record_statistics(
+    time: Res<Time>,
+    mut game_events: EventReader<GameEvent>,
+    mut statistics: ResMut<Statistics>,
+    parents: Query<&Parent>,
+    tiles: Query<&Tile>,
+) {
+    statistics.play_time += time.delta_seconds();
+
+    for event in game_events.iter() {
+        match event {
+            GameEvent::Produced {
+                entity,
+                building_type,
+                items,
+            } => {
+                let tile = parents
+                    .get(*entity)
+                    .ok()
+                    .and_then(|parent| tiles.get(parent.get()).ok());
+
+                statistics.record_production(*building_type, tile, items);
+            }
+            GameEvent::Sold {
+                item_type,
+                quantity,
+                revenue,
+                boosted,
+            } => {
+                let item = statistics.item_mut(*item_type);
+                item.sold += quantity;
+                item.revenue += revenue;
+
+                if *boosted {
+                    statistics.boosts_used += 1;
+                }
+            }
+            GameEvent::Bought {
+                item_type,
+                quantity,
+                cost,
+            } => {
+                let item = statistics.item_mut(*item_type);
+                item.bought += quantity;
+                item.cost += cost;
+            }
+            GameEvent::Upgraded { cost, .. } => statistics.spent_on_upgrades += cost,
+            GameEvent::Constructed { cost, .. } => statistics.spent_on_construction += cost,
+            GameEvent::LandBought { cost } => statistics.spent_on_land += cost,
+            GameEvent::Demolished { refund, .. } => statistics.demolition_refunds += refund,
+            GameEvent::Collected { .. } => {}
+        }
+    }
+}
+
+#[allow(clippy::complexity)]
+fn toggle_statistics(
+    keybinds: Res<Keybinds>,
+    keys: Res<Input<KeyCode>>,
+    camera_state: Res<State<CameraState>>,
+    mut previous_camera_state: ResMut<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    ui_state: Res<State<UiState>>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+    construct_state: Res<State<ConstructPhase>>,
+) {
+    if construct_state.0 != ConstructPhase::Normal {
+        return;
+    }
+
+    if ui_state.0 == UiState::Statistics
+        && (keys.just_pressed(keybinds.toggle_statistics) || keys.just_pressed(keybinds.exit_uistate))
+    {
+        next_ui_state.set(UiState::None);
+        send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+    } else if ui_state.0 == UiState::None && keys.just_pressed(keybinds.toggle_statistics) {
+        previous_camera_state.0 = Some(camera_state.0.clone());
+        send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::Frozen));
+        next_ui_state.set(UiState::Statistics);
+    }
+}
+
+// Marker
+#[derive(Component)]
+struct StatisticsUIRoot;
+
+// Marker
+#[derive(Component)]
+struct StatsTableRows;
+
+#[derive(Component)]
+struct SortButton(StatsColumn);
+
+fn format_play_time(seconds: f32) -> String {
+    let minutes = seconds as u32 / 60;
+
+    format!("{}h {}m", minutes / 60, minutes % 60)
+}
+
+fn summary_text(statistics: &Statistics) -> String {
+    let revenue = statistics.items.iter().map(|item| item.revenue).sum::<u32>();
+    let cost = statistics.items.iter().map(|item| item.cost).sum::<u32>();
+
+    let mut lines = vec![
+        format!("Play time: {}", format_play_time(statistics.play_time)),
+        format!("Sales: +${revenue}"),
+        format!("Market purchases: -${cost}"),
+        format!("Upgrades: -${}", statistics.spent_on_upgrades),
+        format!("Construction: -${}", statistics.spent_on_construction),
+        format!("Land: -${}", statistics.spent_on_land),
+        format!("Demolition refunds: +${}", statistics.demolition_refunds),
+        format!("Boosted sales: {}", statistics.boosts_used),
+        String::new(),
+        "Produced by type".to_string(),
+    ];
+
+    let mut by_type = statistics.produced_by_type.clone();
+    by_type.sort_by_key(|(_, produced)| std::cmp::Reverse(*produced));
+
+    for (building_type, produced) in by_type.iter() {
+        lines.push(format!("  {}: {produced}", building_type.get_name()));
+    }
+
+    let mut by_building = statistics
+        .produced_by_building
+        .iter()
+        .collect::<Vec<&BuildingStatistics>>();
+    by_building.sort_by_key(|building| std::cmp::Reverse(building.produced));
+
+    if !by_building.is_empty() {
+        lines.push(String::new());
+        lines.push("Top buildings".to_string());
+    }
+
+    for building in by_building.iter().take(5) {
+        lines.push(format!(
+            "  {} ({}, {}): {}",
+            building.building_type.get_name(),
+            building.x,
+            building.z,
+            building.produced
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[allow(clippy::complexity)]
+fn draw_statistics(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    statistics: Res<Statistics>,
+    stats_sort: Res<StatsSort>,
+) {
+    // UI takes up half the screen & renders at a ratio of 1:1.777
+    let mut ui_width = primary_window.single().resolution.width() / 2.0;
+    let mut ui_height = ui_width / (1920.0 / 1080.0);
+
+    if ui_height > primary_window.single().resolution.height() {
+        ui_height = primary_window.single().resolution.height() / 2.0;
+        ui_width = ui_height * (1920.0 / 1080.0);
+    }
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.9).into(),
+            ..default()
+        })
+        .insert(Name::new("Statistics UI Root"))
+        .insert(StatisticsUIRoot)
+        .with_children(|commands| {
+            commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(ui_width), Val::Px(ui_height)),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceEvenly,
+                        align_items: AlignItems::Center,
+                        align_self: AlignSelf::Center,
+                        margin: UiRect::left(Val::Px(
+                            // Offset required for the centre of the UI width to align with centre of screen
+                            (primary_window.single().resolution.width() - ui_width) / 2.0,
+                        )),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.13, 0.14, 0.26).into(),
+                    ..default()
+                })
+                .insert(Name::new("Layout"))
+                .with_children(|commands| {
+                    // Summary
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(35.0), Val::Percent(90.0)),
+                                flex_direction: FlexDirection::Column,
+                                ..default()
+                            },
+                            background_color: Color::rgb(0.17, 0.19, 0.36).into(),
+                            ..default()
+                        })
+                        .insert(Name::new("Summary container"))
+                        .with_children(|commands| {
+                            commands.spawn(
+                                TextBundle::from_section(
+                                    "Statistics",
+                                    TextStyle {
+                                        font: asset_server.load("font.otf"),
+                                        font_size: ui_width / 30.0,
+                                        color: Color::rgb(0.55, 0.44, 0.95),
+                                    },
+                                )
+                                .with_style(Style {
+                                    margin: UiRect::all(Val::Percent(4.0)),
+                                    ..default()
+                                }),
+                            );
+
+                            commands.spawn(
+                                TextBundle::from_section(
+                                    summary_text(&statistics),
+                                    TextStyle {
+                                        font: asset_server.load("font.otf"),
+                                        font_size: ui_width / 55.0,
+                                        color: Color::WHITE,
+                                    },
+                                )
+                                .with_style(Style {
+                                    margin: UiRect::horizontal(Val::Percent(4.0)),
+                                    ..default()
+                                }),
+                            );
+                        });
+
+                    // Item table
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(60.0), Val::Percent(90.0)),
+                                flex_direction: FlexDirection::Column,
+                                ..default()
+                            },
+                            background_color: Color::rgb(0.17, 0.19, 0.36).into(),
+                            ..default()
+                        })
+                        .insert(Name::new("Table container"))
+                        .with_children(|commands| {
+                            // Header - click a column to sort by it
+                            commands
+                                .spawn(NodeBundle {
+                                    style: Style {
+                                        size: Size::new(Val::Percent(100.0), Val::Percent(8.0)),
+                                        flex_direction: FlexDirection::Row,
+                                        ..default()
+                                    },
+                                    ..default()
+                                })
+                                .insert(Name::new("Header"))
+                                .with_children(|commands| {
+                                    for column in StatsColumn::ALL {
+                                        commands
+                                            .spawn(ButtonBundle {
+                                                style: Style {
+                                                    size: Size::new(
+                                                        Val::Percent(100.0 / StatsColumn::ALL.len() as f32),
+                                                        Val::Percent(100.0),
+                                                    ),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                background_color: Color::rgb(0.22, 0.25, 0.48).into(),
+                                                ..default()
+                                            })
+                                            .insert(SortButton(column))
+                                            .with_children(|commands| {
+                                                commands.spawn(TextBundle::from_section(
+                                                    column.title(),
+                                                    TextStyle {
+                                                        font: asset_server.load("font.otf"),
+                                                        font_size: ui_width / 55.0,
+                                                        color: Color::WHITE,
+                                                    },
+                                                ));
+                                            });
+                                    }
+                                });
+
+                            commands
+                                .spawn(NodeBundle {
+                                    style: Style {
+                                        size: Size::new(Val::Percent(100.0), Val::Percent(92.0)),
+                                        flex_direction: FlexDirection::Column,
+                                        ..default()
+                                    },
+                                    ..default()
+                                })
+                                .insert(Name::new("Rows"))
+                                .insert(StatsTableRows)
+                                .with_children(|commands| {
+                                    spawn_table_rows(commands, &asset_server, &statistics, &stats_sort, ui_width);
+                                });
+                        });
+                });
+        });
+}
+
+fn spawn_table_rows(
+    commands: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    statistics: &Statistics,
+    stats_sort: &StatsSort,
+    base_width: f32,
+) {
+    let mut items = statistics.items.iter().collect::<Vec<&ItemStatistics>>();
+
+    items.sort_by(|a, b| {
+        let ordering = stats_sort.column.compare(a, b);
+
+        if stats_sort.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    if items.is_empty() {
+        commands.spawn(
+            TextBundle::from_section(
+                "Nothing produced or traded yet",
+                TextStyle {
+                    font: asset_server.load("font.otf"),
+                    font_size: base_width / 55.0,
+                    color: Color::GRAY,
+                },
+            )
+            .with_style(Style {
+                margin: UiRect::all(Val::Percent(2.0)),
+                ..default()
+            }),
+        );
+    }
+
+    for item in items {
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(100.0), Val::Auto),
+                    flex_direction: FlexDirection::Row,
+                    margin: UiRect::top(Val::Percent(0.5)),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|commands| {
+                for column in StatsColumn::ALL {
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(100.0 / StatsColumn::ALL.len() as f32), Val::Auto),
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|commands| {
+                            commands.spawn(TextBundle::from_section(
+                                column.cell(item),
+                                TextStyle {
+                                    font: asset_server.load("font.otf"),
+                                    font_size: base_width / 60.0,
+                                    color: if column == stats_sort.column {
+                                        Color::rgb(0.55, 0.44, 0.95)
+                                    } else {
+                                        Color::WHITE
+                                    },
+                                },
+                            ));
+                        });
+                }
+            });
+    }
+}
+
+fn undraw_statistics(mut commands: Commands, ui_root: Query<Entity, With<StatisticsUIRoot>>) {
+    for entity in ui_root.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::complexity)]
+fn sort_button_interaction(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &SortButton), Changed<Interaction>>,
+    mut stats_sort: ResMut<StatsSort>,
+) {
+    for (interaction, mut background_colour, SortButton(column)) in interaction_query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => {
+                if stats_sort.column == *column {
+                    stats_sort.descending = !stats_sort.descending;
+                } else {
+                    stats_sort.column = *column;
+                    stats_sort.descending = true;
+                }
+            }
+            Interaction::Hovered => *background_colour = Color::rgb(0.34, 0.37, 0.60).into(),
+            _ => *background_colour = Color::rgb(0.22, 0.25, 0.48).into(),
+        }
+    }
+}
+
+fn redraw_table(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    statistics: Res<Statistics>,
+    stats_sort: Res<StatsSort>,
+    table_rows: Query<Entity, With<StatsTableRows>>,
+) {
+    if !stats_sort.is_changed() {
+        return;
+    }
+
+    // Same as in draw_statistics
+    let mut ui_width = primary_window.single().resolution.width() / 2.0;
+    let ui_height = ui_width / (1920.0 / 1080.0);
+
+    if ui_height > primary_window.single().resolution.height() {
+        ui_width = primary_window.single().resolution.height() / 2.0 * (1920.0 / 1080.0);
+    }
+
+    for entity in table_rows.iter() {
+        commands.entity(entity).despawn_descendants();
+        commands.entity(entity).with_children(|commands| {
+            spawn_table_rows(commands, &asset_server, &statistics, &stats_sort, ui_width);
+        });
+    }
+}
@@ -1,6 +1,9 @@
 use crate::*;
 use bevy::window::PrimaryWindow;
 
+// Share of the construct price and upgrade costs paid back on demolition, in percent
+const DEMOLISH_REFUND_PERCENT: u32 = 50;
+
 pub struct UpgradePlugin;
 
 impl Plugin for UpgradePlugin {
@@ -9,7 +12,16 @@ impl Plugin for UpgradePlugin {
             .add_system(draw_ui.in_schedule(OnEnter(UiState::Upgrade)))
             .add_system(undraw_ui.in_schedule(OnExit(UiState::Upgrade)))
             .add_systems(
-                (exit_uistate, collect_button_interaction, upgrade_button_interaction)
+                (
+                    exit_uistate,
+                    collect_button_interaction,
+                    upgrade_button_interaction,
+                    demolish_button_interaction,
+                    move_button_interaction,
+                    rush_button_interaction,
+                    update_construction_text,
+                    update_cycle_progress,
+                )
                     .in_set(OnUpdate(UiState::Upgrade)),
             );
     }
@@ -75,6 +87,36 @@ pub struct YieldCountText {
 #[derive(Component)]
 struct UpgradeButton;
 
+// Marker
+#[derive(Component)]
+struct MoveButton;
+
+// Marker
+#[derive(Component)]
+struct CycleProgressFill;
+
+// Marker
+#[derive(Component)]
+struct RushButton;
+
+// Marker
+#[derive(Component)]
+struct RushButtonText;
+
+// Marker
+#[derive(Component)]
+struct ConstructionText;
+
+// Needs a second click to confirm
+#[derive(Component)]
+struct DemolishButton {
+    confirming: bool,
+}
+
+// Marker
+#[derive(Component)]
+struct DemolishButtonText;
+
 #[allow(clippy::complexity)]
 fn collect_button_interaction(
     mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<CollectButton>)>,
@@ -86,6 +128,7 @@ fn collect_button_interaction(
         Query<(&mut Text, &UpgradeMaterialText), With<UpgradeMaterialText>>,
     )>,
     upgrade_data: Res<UpgradeData>,
+    mut send_game_event: EventWriter<GameEvent>,
 ) {
     for (interaction, mut background_colour) in interaction_query.iter_mut() {
         let mut target_building = None;
@@ -107,6 +150,8 @@ fn collect_button_interaction(
 
         match interaction {
             Interaction::Clicked => {
+                let mut collected_items = vec![];
+
                 // Add all yields to inventory and set yields to 0
                 target_building
                     .as_mut()
@@ -121,9 +166,20 @@ fn collect_button_interaction(
                             .unwrap()
                             .quantity += *quantity;
 
+                        if *quantity > 0 {
+                            collected_items.push((*item_type, *quantity));
+                        }
+
                         *quantity = 0;
                     });
 
+                if !collected_items.is_empty() {
+                    send_game_event.send(GameEvent::Collected {
+                        building_type: target_building.as_ref().unwrap().building_type,
+                        items: collected_items,
+                    });
+                }
+
                 for mut text in text_set.p0().iter_mut() {
                     text.sections[0].value = "x0".to_string();
                 }
@@ -177,6 +233,7 @@ fn upgrade_button_interaction(
     mut upgrade_material_images: Query<(&mut UiImage, &UpgradeMaterialImage)>,
     item_icons: Res<ItemIcons>,
     mut timers: ResMut<Timers>,
+    mut send_game_event: EventWriter<GameEvent>,
 ) {
     let mut target_building = None;
 
@@ -247,6 +304,12 @@ fn upgrade_button_interaction(
 
                     timers.update_timer_speed(&selected_building.building.unwrap(), building.speed);
 
+                    send_game_event.send(GameEvent::Upgraded {
+                        building_type: building.building_type,
+                        level: building.level,
+                        cost: level_stats.upgrade_cost,
+                    });
+
                     // As the level changed, these also need to change
                     let level_stats = &upgrade_data.map[&building.building_type][&building.level];
                     let next_level_stats = &upgrade_data.map[&building.building_type].get(&(building.level + 1));
@@ -351,6 +414,253 @@ fn upgrade_button_interaction(
     }
 }
 
+/// Money paid back for demolishing a building - part of what was spent constructing and upgrading it
+fn demolish_refund(building: &Building, construct_inventory: &ConstructInventory, upgrade_data: &UpgradeData) -> u32 {
+    let construct_price = construct_inventory
+        .items
+        .iter()
+        .find(|item| item.building_type == building.building_type)
+        .map(|item| item.price)
+        .unwrap_or_default();
+
+    let upgrade_costs = (1..building.level)
+        .map(|level| upgrade_data.map[&building.building_type][&level].upgrade_cost)
+        .sum::<u32>();
+
+    (construct_price + upgrade_costs) * DEMOLISH_REFUND_PERCENT / 100
+}
+
+#[allow(clippy::complexity)]
+fn demolish_button_interaction(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &mut DemolishButton), Changed<Interaction>>,
+    mut demolish_text: Query<&mut Text, With<DemolishButtonText>>,
+    mut commands: Commands,
+    buildings: Query<&Building>,
+    selected_building: Res<SelectedBuilding>,
+    mut inventory: ResMut<Inventory>,
+    mut construct_inventory: ResMut<ConstructInventory>,
+    upgrade_data: Res<UpgradeData>,
+    mut timers: ResMut<Timers>,
+    previous_camera_state: Res<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+    mut send_game_event: EventWriter<GameEvent>,
+) {
+    for (interaction, mut background_colour, mut demolish_button) in interaction_query.iter_mut() {
+        let Some((entity, building)) = selected_building
+            .building
+            .and_then(|entity| buildings.get(entity).ok().map(|building| (entity, building)))
+        else {
+            return;
+        };
+
+        match interaction {
+            Interaction::Clicked => {
+                let refund = demolish_refund(building, &construct_inventory, &upgrade_data);
+
+                if !demolish_button.confirming {
+                    demolish_button.confirming = true;
+
+                    for mut text in demolish_text.iter_mut() {
+                        text.sections[0].value = format!("Confirm +${refund}");
+                    }
+
+                    continue;
+                }
+
+                // Whatever the building produced goes to the inventory
+                let mut collected_items = vec![];
+
+                for (item_type, quantity) in building.yields.iter() {
+                    inventory
+                        .items
+                        .iter_mut()
+                        .find(|item| item.item_type == *item_type)
+                        .unwrap()
+                        .quantity += quantity;
+
+                    if *quantity > 0 {
+                        collected_items.push((*item_type, *quantity));
+                    }
+                }
+
+                if !collected_items.is_empty() {
+                    send_game_event.send(GameEvent::Collected {
+                        building_type: building.building_type,
+                        items: collected_items,
+                    });
+                }
+
+                inventory.balance += refund;
+
+                if let Some(item) = construct_inventory
+                    .items
+                    .iter_mut()
+                    .find(|item| item.building_type == building.building_type)
+                {
+                    item.quantity += 1;
+                }
+
+                timers.remove_timer(&entity);
+                commands.entity(entity).despawn_recursive();
+
+                send_game_event.send(GameEvent::Demolished {
+                    building_type: building.building_type,
+                    refund,
+                });
+
+                next_ui_state.set(UiState::None);
+                send_change_camera_state_event.send(ChangeCameraStateEvent(previous_camera_state.0.clone().unwrap()));
+            }
+            Interaction::Hovered => *background_colour = Color::rgb(0.34, 0.37, 0.60).into(),
+            _ => *background_colour = Color::rgb(0.22, 0.25, 0.48).into(),
+        }
+    }
+}
+
+/// Picks the selected building up so it can be placed somewhere else, like a newly bought one
+#[allow(clippy::complexity)]
+fn move_button_interaction(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<MoveButton>)>,
+    mut commands: Commands,
+    selected_building: Res<SelectedBuilding>,
+    buildings: Query<&Building>,
+    mut building_stash: ResMut<BuildingStash>,
+    mut next_construct_state: ResMut<NextState<ConstructPhase>>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+) {
+    for (interaction, mut background_colour) in interaction_query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => {
+                let Some(entity) = selected_building.building else {
+                    return;
+                };
+
+                // The building stays on its old tile until it is placed, so it is never missing from a save
+                commands.entity(entity).insert(Visibility::Hidden);
+
+                building_stash.building_type = None;
+                building_stash.relocating = Some(entity);
+                building_stash.rotation = buildings.get(entity).map_or(0, |building| building.rotation);
+                next_construct_state.set(ConstructPhase::Preview);
+                next_ui_state.set(UiState::None);
+                send_change_camera_state_event.send(ChangeCameraStateEvent(CameraState::ConstructPreview));
+            }
+            Interaction::Hovered => *background_colour = Color::rgb(0.34, 0.37, 0.60).into(),
+            _ => *background_colour = Color::rgb(0.22, 0.25, 0.48).into(),
+        }
+    }
+}
+
+/// Spends Gold Coins to finish the selected building's construction right away
+#[allow(clippy::complexity)]
+fn rush_button_interaction(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<RushButton>)>,
+    selected_building: Res<SelectedBuilding>,
+    mut under_construction: Query<&mut UnderConstruction>,
+    mut inventory: ResMut<Inventory>,
+    construct_data: Res<ConstructData>,
+) {
+    for (interaction, mut background_colour) in interaction_query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => {
+                let Some(mut under_construction) = selected_building
+                    .building
+                    .and_then(|entity| under_construction.get_mut(entity).ok())
+                else {
+                    return;
+                };
+
+                let cost = under_construction.rush_cost(&construct_data);
+                let Some(gold) = inventory
+                    .items
+                    .iter_mut()
+                    .find(|item| item.item_type == ItemType::GoldCoin)
+                else {
+                    return;
+                };
+
+                if gold.quantity >= cost {
+                    gold.quantity -= cost;
+                    under_construction.rush();
+                }
+            }
+            Interaction::Hovered => *background_colour = Color::rgb(0.34, 0.37, 0.60).into(),
+            _ => *background_colour = Color::rgb(0.22, 0.25, 0.48).into(),
+        }
+    }
+}
+
+/// Counts the construction panel down, and closes it once the building is finished
+#[allow(clippy::complexity)]
+fn update_construction_text(
+    mut param_set: ParamSet<(
+        Query<&mut Text, With<ConstructionText>>,
+        Query<&mut Text, With<RushButtonText>>,
+    )>,
+    selected_building: Res<SelectedBuilding>,
+    under_construction: Query<&UnderConstruction>,
+    construct_data: Res<ConstructData>,
+    previous_camera_state: Res<PreviousCameraState>,
+    mut send_change_camera_state_event: EventWriter<ChangeCameraStateEvent>,
+    mut next_ui_state: ResMut<NextState<UiState>>,
+) {
+    if param_set.p0().is_empty() {
+        return;
+    }
+
+    let Some(under_construction) = selected_building
+        .building
+        .and_then(|entity| under_construction.get(entity).ok())
+    else {
+        // The full panel has to be drawn from scratch
+        next_ui_state.set(UiState::None);
+        send_change_camera_state_event.send(ChangeCameraStateEvent(
+            previous_camera_state.0.clone().unwrap_or(CameraState::CursorLocked),
+        ));
+        return;
+    };
+
+    let progress = format!(
+        "Under construction - {:.0}% done, {:.0}s left",
+        under_construction.timer.percent() * 100.0,
+        under_construction.timer.remaining_secs().ceil()
+    );
+    let rush = format!("Finish for {} Gold", under_construction.rush_cost(&construct_data));
+
+    for mut text in param_set.p0().iter_mut() {
+        if text.sections[0].value != progress {
+            text.sections[0].value = progress.clone();
+        }
+    }
+
+    for mut text in param_set.p1().iter_mut() {
+        if text.sections[0].value != rush {
+            text.sections[0].value = rush.clone();
+        }
+    }
+}
+
+/// Fills the progress bar as the selected building works through its production cycle
+fn update_cycle_progress(
+    mut fills: Query<&mut Style, With<CycleProgressFill>>,
+    selected_building: Res<SelectedBuilding>,
+    timers: Res<Timers>,
+) {
+    let progress = selected_building
+        .building
+        .and_then(|entity| timers.progress(&entity))
+        .unwrap_or_default();
+    let width = Val::Percent(progress * 100.0);
+
+    for mut style in fills.iter_mut() {
+        if style.size.width != width {
+            style.size.width = width;
+        }
+    }
+}
+
 #[derive(Resource, Default)]
 pub struct SelectedBuilding {
     pub building: Option<Entity>,
@@ -367,6 +677,11 @@ fn draw_ui(
     mut upgrade_target_events: EventReader<UpgradeTarget>,
     mut selected_building_resource: ResMut<SelectedBuilding>,
     upgrade_data: Res<UpgradeData>,
+    synergies: Query<&Synergies>,
+    adjacency_data: Res<AdjacencyData>,
+    disconnected: Query<(), With<Disconnected>>,
+    under_construction: Query<(), With<UnderConstruction>>,
+    terrain_data: Res<TerrainData>,
 ) {
     // UI takes up half the screen & renders at a ratio of 1:1.777
     let mut inventory_width = primary_window.single().resolution.width() / 2.0;
@@ -395,12 +710,48 @@ fn draw_ui(
     // 100% sure that this will not panic
     let target_building = target_building.unwrap();
 
+    // Nothing can be collected or upgraded until the building is finished
+    if under_construction.contains(target.target_entity) {
+        draw_construction_ui(
+            &mut commands,
+            &asset_server,
+            inventory_width / 2.0,
+            target_building.building_type,
+        );
+        return;
+    }
+
+    // Decorations have no levels or yields, so they get a smaller panel
+    if target_building.building_type.is_decoration() {
+        draw_decoration_ui(
+            &mut commands,
+            &asset_server,
+            inventory_width / 2.0,
+            target_building.building_type,
+            &adjacency_data,
+        );
+        return;
+    }
+
     let level_stats = &upgrade_data.map[&target_building.building_type][&target_building.level];
     // If this is None, then we are at the MAX level
     let next_level_stats = &upgrade_data.map[&target_building.building_type].get(&(target_building.level + 1));
 
     trace!("TARGET BUILDING {:?}", target_building);
 
+    let synergy_lines = synergies
+        .get(target.target_entity)
+        .map(|synergies| synergies.describe(&adjacency_data, &terrain_data))
+        .unwrap_or_default();
+
+    let synergy_text = if synergy_lines.is_empty() {
+        "No neighbour bonuses".to_string()
+    } else {
+        synergy_lines.join("\n")
+    };
+
+    let is_disconnected = disconnected.contains(target.target_entity);
+
     commands
         .spawn(NodeBundle {
             style: Style {
@@ -550,6 +901,72 @@ fn draw_ui(
                                                     ..default()
                                                 })
                                                 .insert(SpeedText);
+
+                                            // Progress through the current production cycle
+                                            commands
+                                                .spawn(NodeBundle {
+                                                    style: Style {
+                                                        size: Size::new(
+                                                            Val::Percent(90.0),
+                                                            Val::Px(inventory_width / 100.0),
+                                                        ),
+                                                        margin: UiRect::new(
+                                                            Val::Percent(5.0),
+                                                            Val::Percent(5.0),
+                                                            Val::Px(0.0),
+                                                            Val::Percent(5.0),
+                                                        ),
+                                                        ..default()
+                                                    },
+                                                    background_color: Color::rgb(0.22, 0.25, 0.48).into(),
+                                                    ..default()
+                                                })
+                                                .with_children(|commands| {
+                                                    commands
+                                                        .spawn(NodeBundle {
+                                                            style: Style {
+                                                                size: Size::new(Val::Percent(0.0), Val::Percent(100.0)),
+                                                                ..default()
+                                                            },
+                                                            background_color: Color::rgb(0.55, 0.44, 0.95).into(),
+                                                            ..default()
+                                                        })
+                                                        .insert(CycleProgressFill);
+                                                });
+
+                                            commands.spawn(
+                                                TextBundle::from_section(
+                                                    synergy_text,
+                                                    TextStyle {
+                                                        font: asset_server.load("font.otf"),
+                                                        font_size: inventory_width / 52.0,
+                                                        color: Color::rgb(0.55, 0.44, 0.95),
+                                                    },
+                                                )
+                                                .with_style(Style {
+                                                    margin: UiRect::horizontal(Val::Percent(5.0)),
+                                                    ..default()
+                                                }),
+                                            );
+
+                                            if is_disconnected {
+                                                commands.spawn(
+                                                    TextBundle::from_section(
+                                                        format!(
+                                                            "No road to the City Centre - {DISCONNECTED_PRODUCTION}% production"
+                                                        ),
+                                                        TextStyle {
+                                                            font: asset_server.load("font.otf"),
+                                                            font_size: inventory_width / 52.0,
+                                                            color: Color::rgb(0.95, 0.35, 0.35),
+                                                        },
+                                                    )
+                                                    .with_style(Style {
+                                                        margin: UiRect::all(Val::Percent(5.0)),
+                                                        ..default()
+                                                    }),
+                                                );
+                                            }
                                         });
 
                                     for i in 0..=2 {
@@ -936,13 +1353,66 @@ fn draw_ui(
                                 .spawn(NodeBundle {
                                     style: Style {
                                         size: Size::new(Val::Percent(100.0), Val::Percent(20.0)),
-                                        justify_content: JustifyContent::Center,
+                                        justify_content: JustifyContent::SpaceEvenly,
                                         align_items: AlignItems::Center,
                                         ..default()
                                     },
                                     ..default()
                                 })
                                 .with_children(|commands| {
+                                    // The City Centre is the heart of the city and can't be torn down
+                                    if target_building.building_type != BuildingType::CityCentre {
+                                        commands
+                                            .spawn(ButtonBundle {
+                                                style: Style {
+                                                    size: Size::new(Val::Percent(30.0), Val::Percent(50.0)),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                background_color: Color::rgb(0.22, 0.25, 0.48).into(),
+                                                ..default()
+                                            })
+                                            .insert(MoveButton)
+                                            .insert(Name::new("Move button"))
+                                            .with_children(|commands| {
+                                                commands.spawn(TextBundle::from_section(
+                                                    "Move",
+                                                    TextStyle {
+                                                        font: asset_server.load("font.otf"),
+                                                        font_size: inventory_width / 30.0,
+                                                        color: Color::WHITE,
+                                                    },
+                                                ));
+                                            });
+
+                                        commands
+                                            .spawn(ButtonBundle {
+                                                style: Style {
+                                                    size: Size::new(Val::Percent(30.0), Val::Percent(50.0)),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                background_color: Color::rgb(0.22, 0.25, 0.48).into(),
+                                                ..default()
+                                            })
+                                            .insert(DemolishButton { confirming: false })
+                                            .insert(Name::new("Demolish button"))
+                                            .with_children(|commands| {
+                                                commands
+                                                    .spawn(TextBundle::from_section(
+                                                        "Demolish",
+                                                        TextStyle {
+                                                            font: asset_server.load("font.otf"),
+                                                            font_size: inventory_width / 30.0,
+                                                            color: Color::WHITE,
+                                                        },
+                                                    ))
+                                                    .insert(DemolishButtonText);
+                                            });
+                                    }
+
                                     commands
                                         .spawn(ButtonBundle {
                                             style: Style {
@@ -972,6 +1442,201 @@ fn draw_ui(
         });
 }
 
+fn draw_decoration_ui(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    ui_width: f32,
+    building_type: BuildingType,
+    adjacency_data: &AdjacencyData,
+) {
+    let attractiveness = adjacency_data
+        .attractiveness
+        .iter()
+        .find(|(decoration_type, _)| *decoration_type == building_type)
+        .map(|(_, attractiveness)| *attractiveness)
+        .unwrap_or_default();
+
+    let attracted = adjacency_data
+        .attracted
+        .iter()
+        .map(|attracted_type| attracted_type.get_name())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    draw_small_panel(
+        commands,
+        asset_server,
+        ui_width,
+        building_type,
+        format!(
+            "+{attractiveness} attractiveness for every {attracted} within {} tiles",
+            adjacency_data.attraction_radius
+        ),
+        (),
+        |commands| {
+            commands
+                .spawn(small_panel_button())
+                .insert(MoveButton)
+                .insert(Name::new("Move button"))
+                .with_children(|commands| {
+                    commands.spawn(TextBundle::from_section(
+                        "Move",
+                        TextStyle {
+                            font: asset_server.load("font.otf"),
+                            font_size: ui_width / 16.0,
+                            color: Color::WHITE,
+                        },
+                    ));
+                });
+        },
+    );
+}
+
+/// Progress and rush panel - the text is filled in by update_construction_text
+fn draw_construction_ui(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    ui_width: f32,
+    building_type: BuildingType,
+) {
+    draw_small_panel(
+        commands,
+        asset_server,
+        ui_width,
+        building_type,
+        String::new(),
+        ConstructionText,
+        |commands| {
+            commands
+                .spawn(small_panel_button())
+                .insert(RushButton)
+                .insert(Name::new("Rush button"))
+                .with_children(|commands| {
+                    commands
+                        .spawn(TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font: asset_server.load("font.otf"),
+                                font_size: ui_width / 20.0,
+                                color: Color::WHITE,
+                            },
+                        ))
+                        .insert(RushButtonText);
+                });
+        },
+    );
+}
+
+/// Panel for buildings without levels - a title, one line of text, then the caller's button
+/// next to Demolish
+fn draw_small_panel(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    ui_width: f32,
+    building_type: BuildingType,
+    body: String,
+    body_marker: impl Bundle,
+    extra_button: impl FnOnce(&mut ChildBuilder),
+) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+            ..default()
+        })
+        .insert(Name::new("UI Root"))
+        .insert(RootUINode)
+        .with_children(|commands| {
+            commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(ui_width), Val::Auto),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(ui_width / 20.0)),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.13, 0.14, 0.26).into(),
+                    ..default()
+                })
+                .with_children(|commands| {
+                    commands.spawn(TextBundle::from_section(
+                        building_type.get_name(),
+                        TextStyle {
+                            font: asset_server.load("font.otf"),
+                            font_size: ui_width / 14.0,
+                            color: Color::rgb(0.55, 0.44, 0.95),
+                        },
+                    ));
+
+                    commands
+                        .spawn(
+                            TextBundle::from_section(
+                                body,
+                                TextStyle {
+                                    font: asset_server.load("font.otf"),
+                                    font_size: ui_width / 22.0,
+                                    color: Color::WHITE,
+                                },
+                            )
+                            .with_style(Style {
+                                margin: UiRect::vertical(Val::Px(ui_width / 20.0)),
+                                ..default()
+                            }),
+                        )
+                        .insert(body_marker);
+
+                    commands
+                        .spawn(NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(100.0), Val::Px(ui_width / 6.0)),
+                                justify_content: JustifyContent::SpaceEvenly,
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|commands| {
+                            extra_button(commands);
+
+                            commands
+                                .spawn(small_panel_button())
+                                .insert(DemolishButton { confirming: false })
+                                .insert(Name::new("Demolish button"))
+                                .with_children(|commands| {
+                                    commands
+                                        .spawn(TextBundle::from_section(
+                                            "Demolish",
+                                            TextStyle {
+                                                font: asset_server.load("font.otf"),
+                                                font_size: ui_width / 16.0,
+                                                color: Color::WHITE,
+                                            },
+                                        ))
+                                        .insert(DemolishButtonText);
+                                });
+                        });
+                });
+        });
+}
+
+fn small_panel_button() -> ButtonBundle {
+    ButtonBundle {
+        style: Style {
+            size: Size::new(Val::Percent(45.0), Val::Percent(100.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        background_color: Color::rgb(0.22, 0.25, 0.48).into(),
+        ..default()
+    }
+}
+
 fn undraw_ui(
     mut commands: Commands,
     ui_root: Query<Entity, With<RootUINode>>,